@@ -4,7 +4,7 @@ use std::time::Duration;
 
 fn main() {
     App::new()
-        .add_plugins((MinimalPlugins, DecayPlugin))
+        .add_plugins((MinimalPlugins, DecayPlugin::default()))
         .add_systems(Startup, startup)
         .add_observer(handle_completed_decay)
         .run();
@@ -18,12 +18,12 @@ fn startup(mut commands: Commands) {
         for max in 15..30 {
             let min = Duration::from_secs(min);
             let max = Duration::from_secs(max);
-            commands.spawn((Item, Decay, DecayDuration::randomized(min, max)));
+            commands.spawn((Item, Decay::for_range(min, max)));
         }
     }
 
     for i in 0..30 {
-        commands.spawn((Item, Decay, DecayDuration::new(Duration::from_secs(i))));
+        commands.spawn((Item, Decay::for_duration(Duration::from_secs(i))));
     }
 }
 
@@ -0,0 +1,229 @@
+use crate::{Decay, DecayCompleted, DecayEntityCommandsExt};
+use bevy::prelude::*;
+
+/// What happens to the contents (children) of a container entity once its decay completes.
+///
+/// The contents are handled before the `DecayAction` of the container, so they can be dropped
+/// before the container is despawned. Contents decay on the `Decay` track, whichever track
+/// the container completed.
+#[derive(Component, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DecayContents {
+    /// The contents are left as they are.
+    #[default]
+    Keep,
+    /// The contents start (or resume) their own decay, with their own `DecayDuration`.
+    StartDecay,
+    /// The decaying contents complete their decay immediately.
+    Complete,
+    /// The contents are detached from the container and dropped at its position.
+    Drop,
+    /// The contents are despawned, along with their own children.
+    Despawn,
+}
+
+/// Applies the `DecayContents` policy of a container whose decay completed to its children.
+pub(crate) fn cascade_contents(
+    commands: &mut Commands,
+    contents: DecayContents,
+    children: &Children,
+    position: Option<Vec3>,
+) {
+    for &child in children.iter() {
+        match contents {
+            DecayContents::Keep => {}
+            DecayContents::StartDecay => {
+                commands.entity(child).insert(Decay);
+            }
+            DecayContents::Complete => {
+                commands.entity(child).complete_decay_now();
+            }
+            DecayContents::Drop => {
+                commands
+                    .entity(child)
+                    .remove_parent()
+                    .queue(move |mut entity: EntityWorldMut| {
+                        // Once detached, the transform of the child is relative to the world.
+                        if let (Some(position), Some(mut transform)) =
+                            (position, entity.get_mut::<Transform>())
+                        {
+                            transform.translation = position;
+                        }
+                    });
+            }
+            DecayContents::Despawn => commands.entity(child).despawn_recursive(),
+        }
+    }
+}
+
+/// One-off command executed on an entity once its decay process is completed.
+///
+/// The command is run with the `EntityCommands` of the entity, then this component is removed.
+/// Unlike an observer, it lets a single entity carry its own completion behavior.
+#[derive(Component)]
+pub struct OnDecayCommand(Option<Box<dyn FnOnce(&mut EntityCommands) + Send + Sync>>);
+
+impl OnDecayCommand {
+    /// Creates a new `OnDecayCommand` executing the given command on completion.
+    pub fn new(command: impl FnOnce(&mut EntityCommands) + Send + Sync + 'static) -> Self {
+        Self(Some(Box::new(command)))
+    }
+}
+
+/// System that executes the `OnDecayCommand` of entities that have completed decaying.
+pub(crate) fn handle_decay_command<T: Component>(
+    trigger: Trigger<DecayCompleted<T>>,
+    mut commands: Commands,
+    mut query: Query<&mut OnDecayCommand>,
+) {
+    for &entity in trigger.iter() {
+        let Ok(mut on_decay) = query.get_mut(entity) else {
+            continue;
+        };
+
+        // The command can only run once, so it is taken out of the component.
+        if let Some(command) = on_decay.0.take() {
+            let mut entity_commands = commands.entity(entity);
+            entity_commands.remove::<OnDecayCommand>();
+            command(&mut entity_commands);
+        }
+    }
+}
+
+/// User event triggered once the decay process of an entity is completed.
+///
+/// The event is either triggered globally or targeted at the entity, then this component is
+/// removed. It lets gameplay wire decay into its own event vocabulary declaratively. The
+/// event type must be registered with [`DecayPlugin::trigger_on_decay`].
+///
+/// [`DecayPlugin::trigger_on_decay`]: crate::DecayPlugin::trigger_on_decay
+#[derive(Component)]
+pub struct OnDecayTrigger<E: Event> {
+    /// The event to trigger, taken once triggered.
+    event: Option<E>,
+    /// Whether the event is targeted at the entity.
+    targeted: bool,
+}
+
+impl<E: Event> OnDecayTrigger<E> {
+    /// Creates a new `OnDecayTrigger` triggering the event globally on completion.
+    pub fn global(event: E) -> Self {
+        Self {
+            event: Some(event),
+            targeted: false,
+        }
+    }
+
+    /// Creates a new `OnDecayTrigger` triggering the event targeted at the entity on completion.
+    pub fn targeted(event: E) -> Self {
+        Self {
+            event: Some(event),
+            targeted: true,
+        }
+    }
+}
+
+/// System that triggers the `OnDecayTrigger<E>` events of entities that have completed decaying.
+pub(crate) fn handle_decay_trigger<E: Event, T: Component>(
+    trigger: Trigger<DecayCompleted<T>>,
+    mut commands: Commands,
+    mut query: Query<&mut OnDecayTrigger<E>>,
+) {
+    for &entity in trigger.iter() {
+        let Ok(mut on_decay) = query.get_mut(entity) else {
+            continue;
+        };
+
+        // The event can only be triggered once, so it is taken out of the component.
+        if let Some(event) = on_decay.event.take() {
+            if on_decay.targeted {
+                commands.trigger_targets(event, entity);
+            } else {
+                commands.trigger(event);
+            }
+
+            commands.entity(entity).remove::<OnDecayTrigger<E>>();
+        }
+    }
+}
+
+/// Spawner creating an entity at the position of a completed decay.
+type DecaySpawner = Box<dyn FnOnce(&mut Commands, Transform) + Send + Sync>;
+
+/// Bundle or scene spawned at the position of an entity once its decay process is completed.
+///
+/// The spawned entity (e.g. remains, debris or a puddle) is placed at the `GlobalTransform`
+/// of the decaying entity, before any cleanup of that entity. This component is then removed.
+#[derive(Component)]
+pub struct OnDecaySpawn(Option<DecaySpawner>);
+
+impl OnDecaySpawn {
+    /// Creates a new `OnDecaySpawn` running the given spawner on completion, with the
+    /// transform of the decaying entity.
+    pub fn new(spawn: impl FnOnce(&mut Commands, Transform) + Send + Sync + 'static) -> Self {
+        Self(Some(Box::new(spawn)))
+    }
+
+    /// Creates a new `OnDecaySpawn` spawning the given bundle on completion.
+    pub fn bundle(bundle: impl Bundle) -> Self {
+        Self::new(move |commands, transform| {
+            commands.spawn(bundle).insert(transform);
+        })
+    }
+
+    /// Creates a new `OnDecaySpawn` spawning the given scene on completion.
+    pub fn scene(scene: Handle<Scene>) -> Self {
+        Self::bundle(SceneRoot(scene))
+    }
+}
+
+/// System that spawns the `OnDecaySpawn` of entities that have completed decaying.
+pub(crate) fn handle_decay_spawn<T: Component>(
+    trigger: Trigger<DecayCompleted<T>>,
+    mut commands: Commands,
+    mut query: Query<(&mut OnDecaySpawn, Option<&GlobalTransform>)>,
+) {
+    for &entity in trigger.iter() {
+        let Ok((mut on_decay, global_transform)) = query.get_mut(entity) else {
+            continue;
+        };
+
+        // The spawn can only happen once, so it is taken out of the component.
+        if let Some(spawn) = on_decay.0.take() {
+            let transform = global_transform
+                .map_or_else(Transform::default, GlobalTransform::compute_transform);
+            spawn(&mut commands, transform);
+
+            commands.entity(entity).remove::<OnDecaySpawn>();
+        }
+    }
+}
+
+/// Loot rolled when an entity bearing this component completes its decay.
+///
+/// Implemented by user components describing what an entity drops (e.g. a corpse decaying
+/// into bones plus random loot). The roll runs with the entity, its transform and the
+/// commands, before any cleanup of the entity. Each loot component must be registered with
+/// [`DecayPlugin::with_loot`].
+///
+/// [`DecayPlugin::with_loot`]: crate::DecayPlugin::with_loot
+pub trait DecayLoot: Component {
+    /// Rolls the loot of the entity that has completed decaying.
+    fn roll(&self, entity: Entity, transform: Transform, commands: &mut Commands);
+}
+
+/// System that rolls the `DecayLoot` of entities that have completed decaying.
+pub(crate) fn handle_decay_loot<L: DecayLoot, T: Component>(
+    trigger: Trigger<DecayCompleted<T>>,
+    mut commands: Commands,
+    query: Query<(&L, Option<&GlobalTransform>)>,
+) {
+    for &entity in trigger.iter() {
+        let Ok((loot, global_transform)) = query.get(entity) else {
+            continue;
+        };
+
+        let transform =
+            global_transform.map_or_else(Transform::default, GlobalTransform::compute_transform);
+        loot.roll(entity, transform, &mut commands);
+    }
+}
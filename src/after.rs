@@ -0,0 +1,47 @@
+use crate::{start_decay, DecayCompleted, DecayRng, DecayStartData, DecayStartedBuffer};
+use bevy::prelude::*;
+
+/// Component delaying the start of the decay of an entity until the decay of another entity
+/// completes, e.g. the wooden frame of a house only starts rotting once its roof collapsed.
+///
+/// While present, the decay of the entity does not start on any track. Once the decay of the
+/// dependency completes on any track registered with a `DecayPlugin`, this component is removed
+/// and the decay starts on every track of the entity. Removing it by hand does not start the
+/// decay, which then begins once its track marker is inserted again.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DecayAfter(pub Entity);
+
+/// Event triggered for an entity whose `DecayAfter` dependency has completed its decay.
+#[derive(Event)]
+pub(crate) struct DecayAfterReleased;
+
+/// System that releases the entities waiting for the decay of the track `T` to complete.
+pub(crate) fn release_decay_after<T: Component>(
+    trigger: Trigger<DecayCompleted<T>>,
+    mut commands: Commands,
+    dependents: Query<(Entity, &DecayAfter)>,
+) {
+    for (entity, after) in dependents.iter() {
+        if !trigger.contains(&after.0) {
+            continue;
+        }
+
+        commands.entity(entity).remove::<DecayAfter>();
+        commands.trigger_targets(DecayAfterReleased, entity);
+    }
+}
+
+/// System that starts the decay of the track `T` for an entity whose dependency has completed.
+pub(crate) fn handle_decay_after_released<T: Component>(
+    trigger: Trigger<DecayAfterReleased>,
+    mut commands: Commands,
+    mut rng: DecayRng,
+    mut started_buffer: Option<ResMut<DecayStartedBuffer<T>>>,
+    mut query: Query<DecayStartData<T>, With<T>>,
+) {
+    let Ok(data) = query.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    start_decay(&mut commands, &mut rng, started_buffer.as_deref_mut(), data);
+}
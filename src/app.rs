@@ -0,0 +1,83 @@
+use crate::{
+    rate::{self, DecayModifierRegistry},
+    register_completed_of, Decay, DecayCompletedOf, DecayingSet,
+};
+use bevy::{ecs::system::IntoObserverSystem, prelude::*};
+use std::borrow::Cow;
+
+/// Extension trait adding decay related registrations to `App`.
+pub trait DecayAppExt {
+    /// Registers an observer handling the decay completions of entities bearing the marker
+    /// component `M`, such as `app.add_decay_completion_handler::<Corpse, _>(handler)`.
+    ///
+    /// The handler observes `DecayCompletedOf<M>`, only holding the completed entities bearing
+    /// the marker, so it does not need to filter them itself. The `DecayCompletedOf<M>` event
+    /// of the `Decay` track is registered if it was not already.
+    fn add_decay_completion_handler<M: Component, Marker>(
+        &mut self,
+        handler: impl IntoObserverSystem<DecayCompletedOf<M>, (), Marker>,
+    ) -> &mut Self;
+
+    /// Registers a system contributing a multiplier to the decay speed of entities, returning
+    /// the multiplier of each entity it applies to.
+    ///
+    /// Meant for crates extending the decay of shared entities (e.g. weather, buffs and
+    /// biomes). Every registered system runs each tick, and its multipliers are stored in the
+    /// `DecayModifiers` of the entities under the given label, such as `"weather"`. The
+    /// multipliers are thus combined in the stable order of their labels, regardless of the
+    /// registration order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a modifier is already registered under the label.
+    fn register_decay_modifier<M>(
+        &mut self,
+        label: impl Into<Cow<'static, str>>,
+        modifier: impl IntoSystem<(), Vec<(Entity, f32)>, M> + 'static,
+    ) -> &mut Self;
+}
+
+impl DecayAppExt for App {
+    fn add_decay_completion_handler<M: Component, Marker>(
+        &mut self,
+        handler: impl IntoObserverSystem<DecayCompletedOf<M>, (), Marker>,
+    ) -> &mut Self {
+        register_completed_of::<M, Decay>(self);
+        self.add_observer(handler)
+    }
+
+    fn register_decay_modifier<M>(
+        &mut self,
+        label: impl Into<Cow<'static, str>>,
+        modifier: impl IntoSystem<(), Vec<(Entity, f32)>, M> + 'static,
+    ) -> &mut Self {
+        // The modifiers are only applied once any is registered, as applying them needs
+        // exclusive access to the world.
+        if !self.world().contains_resource::<DecayModifierRegistry>() {
+            self.add_systems(
+                PreUpdate,
+                rate::apply_registered_modifiers.in_set(DecayingSet::Tick),
+            );
+        }
+
+        let label = label.into();
+        let registered = self
+            .init_resource::<DecayModifierRegistry>()
+            .world()
+            .resource::<DecayModifierRegistry>()
+            .modifiers
+            .iter()
+            .any(|(key, _)| *key == label);
+        assert!(
+            !registered,
+            "a decay modifier is already registered under the label {label}"
+        );
+
+        let id = self.world_mut().register_system(modifier);
+        self.world_mut()
+            .resource_mut::<DecayModifierRegistry>()
+            .modifiers
+            .push((label, id));
+        self
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{start_decay, Decay, DecayRng, DecayStartData, DecayStartedBuffer, DecayTimer};
+use bevy::prelude::*;
+use std::{cmp::Reverse, collections::VecDeque, marker::PhantomData};
+
+/// Maximum number of decays of the track `T` (`Decay` by default) completing each frame.
+///
+/// Finished decays beyond the budget are deferred to the next frames, the most urgent ones
+/// according to their `DecayPriority` completing first. Insert it with
+/// [`DecayPlugin::with_completion_budget`](crate::DecayPlugin::with_completion_budget), and
+/// adjust it at runtime as needed.
+#[derive(Resource, Debug)]
+pub struct DecayCompletionBudget<T: Component = Decay> {
+    /// The maximum number of completions per frame.
+    pub max_per_frame: usize,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayCompletionBudget<T> {
+    /// Creates a new `DecayCompletionBudget` allowing the given completions per frame.
+    pub fn new(max_per_frame: usize) -> Self {
+        Self {
+            max_per_frame,
+            track: PhantomData,
+        }
+    }
+}
+
+/// Priority of the completion of a decay under a `DecayCompletionBudget`.
+///
+/// Critical completions (explosive fields, scripted events) are processed first, while
+/// cosmetic litter may slip a few frames. Entities without it have the `Normal` priority.
+#[derive(Component, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+pub enum DecayPriority {
+    /// Completions that may be deferred the longest, such as cosmetic litter.
+    Low,
+    /// The default priority.
+    #[default]
+    Normal,
+    /// Completions processed before the normal ones.
+    High,
+    /// Completions always processed first.
+    Critical,
+}
+
+/// Maximum number of decays of the track `T` (`Decay` by default) running at once.
+///
+/// Decays added beyond the cap are kept in a pending queue, and start as soon as running
+/// decays complete or stop, keeping the completion load of busy servers predictable. Only new
+/// decays are queued: resumed decays keep their timer and resume right away. Insert it with
+/// [`DecayPlugin::with_concurrency_cap`](crate::DecayPlugin::with_concurrency_cap), and adjust
+/// it at runtime as needed.
+#[derive(Resource, Debug)]
+pub struct DecayConcurrency<T: Component = Decay> {
+    /// The maximum number of decays running at once.
+    pub max_active: usize,
+    /// The order in which pending decays start.
+    pub order: DecayQueueOrder,
+    /// The decays waiting for a free slot.
+    pending: VecDeque<Entity>,
+    /// The decays started since the slots were last counted.
+    admitted: usize,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayConcurrency<T> {
+    /// Creates a new `DecayConcurrency` allowing the given decays at once.
+    pub fn new(max_active: usize, order: DecayQueueOrder) -> Self {
+        Self {
+            max_active,
+            order,
+            pending: VecDeque::new(),
+            admitted: 0,
+            track: PhantomData,
+        }
+    }
+
+    /// Iterates over the decays waiting for a free slot.
+    pub fn pending(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.pending.iter().copied()
+    }
+
+    /// Admits a new decay if a slot is free and no decay is waiting, or queues it otherwise.
+    ///
+    /// Returns whether the decay may start right away.
+    pub(crate) fn admit(&mut self, entity: Entity, active: usize) -> bool {
+        if self.pending.is_empty() && active + self.admitted < self.max_active {
+            self.admitted += 1;
+            true
+        } else {
+            self.pending.push_back(entity);
+            false
+        }
+    }
+}
+
+/// Order in which the decays pending under a `DecayConcurrency` cap start.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub enum DecayQueueOrder {
+    /// The decays start in the order they were added.
+    #[default]
+    Fifo,
+    /// The decays start by `DecayPriority`, in the order they were added within a priority.
+    Priority,
+}
+
+/// System that starts the pending decays of the track `T` as slots free up.
+pub(crate) fn start_pending_decays<T: Component>(
+    mut commands: Commands,
+    mut rng: DecayRng,
+    mut started_buffer: Option<ResMut<DecayStartedBuffer<T>>>,
+    mut concurrency: ResMut<DecayConcurrency<T>>,
+    priorities: Query<&DecayPriority>,
+    active: Query<(), (With<T>, With<DecayTimer<T>>)>,
+    mut query: Query<DecayStartData<T>, With<T>>,
+) {
+    let concurrency = &mut *concurrency;
+
+    // The decays admitted since the last count are running by now.
+    concurrency.admitted = 0;
+
+    // Forget the decays that no longer wait, e.g. despawned or started by other means.
+    concurrency
+        .pending
+        .retain(|&entity| query.get(entity).is_ok_and(|data| data.timer.is_none()));
+
+    if concurrency.order == DecayQueueOrder::Priority {
+        concurrency
+            .pending
+            .make_contiguous()
+            .sort_by_key(|&entity| Reverse(priorities.get(entity).copied().unwrap_or_default()));
+    }
+
+    let free = concurrency.max_active.saturating_sub(active.iter().len());
+    let ready = free.min(concurrency.pending.len());
+    for entity in concurrency.pending.drain(..ready) {
+        if let Ok(data) = query.get_mut(entity) {
+            start_decay(&mut commands, &mut rng, started_buffer.as_deref_mut(), data);
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{on_component, DecayCompletionGuard, DecayDelayTimer, DecayTimer};
+use bevy::prelude::*;
+
+/// Marker component for descendants whose decay follows the decay of their ancestors.
+///
+/// Whenever the decay of an ancestor is cancelled or aborted, i.e. its timer is removed other
+/// than by a completion, the decay of the same track is cancelled on every descendant bearing
+/// this marker, keeping composite objects (e.g. the parts of a wreck) consistent. The cascade
+/// is handled by the `DecayPlugin` of every track registered with
+/// [`DecayPlugin::with_cascade_cancel`].
+///
+/// [`DecayPlugin::with_cascade_cancel`]: crate::DecayPlugin::with_cascade_cancel
+#[derive(Component, Default, Clone, Copy, Debug)]
+pub struct InheritDecayState;
+
+/// System that cancels the decay of the track `T` on the inheriting descendants of entities
+/// whose decay was cancelled.
+pub(crate) fn cascade_decay_cancel<T: Component>(
+    trigger: Trigger<OnRemove, DecayTimer<T>>,
+    mut commands: Commands,
+    guard: Res<DecayCompletionGuard<T>>,
+    children: Query<&Children>,
+    inheriting: Query<
+        (),
+        (
+            With<InheritDecayState>,
+            Or<(With<T>, With<DecayTimer<T>>, With<DecayDelayTimer<T>>)>,
+        ),
+    >,
+) {
+    let entity = trigger.entity();
+
+    // If the decay has completed, the timer is removed because of the completion.
+    if guard.contains(entity) {
+        return;
+    }
+
+    for descendant in children.iter_descendants(entity) {
+        if inheriting.contains(descendant) {
+            commands.entity(descendant).queue(on_component::cancel::<T>);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{DecayCompleted, DecayDuration};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Chains the decay track `From` into the decay track `To`.
+///
+/// When the decay of the `From` track completes, this component is removed and the `To` marker
+/// is inserted along with its `DecayDuration`, starting the next track (e.g. `Wet` decays into
+/// `Dry`, which then decays into `Cracked`).
+///
+/// The chain is handled by the `DecayPlugin<From>` registered with [`DecayPlugin::then`].
+///
+/// [`DecayPlugin::then`]: crate::DecayPlugin::then
+#[derive(Component)]
+pub struct DecayThen<From: Component, To: Component> {
+    /// The marker component of the next decay track.
+    next: To,
+    /// The duration for the next decay track.
+    duration: DecayDuration,
+    /// The marker component of the current decay track.
+    track: PhantomData<From>,
+}
+
+impl<From: Component, To: Component> DecayThen<From, To> {
+    /// Creates a new `DecayThen` starting the `next` track with the given duration.
+    pub fn new(next: To, duration: DecayDuration) -> Self {
+        Self {
+            next,
+            duration,
+            track: PhantomData,
+        }
+    }
+}
+
+/// System that starts the next decay track of entities that have completed the `From` track.
+pub(crate) fn handle_decay_then<From: Component, To: Component>(
+    trigger: Trigger<DecayCompleted<From>>,
+    mut commands: Commands,
+    query: Query<(), With<DecayThen<From, To>>>,
+) {
+    for &entity in trigger.iter() {
+        if !query.contains(entity) {
+            continue;
+        }
+
+        // Replace the chain with the next track marker and its duration.
+        commands.entity(entity).queue(|mut entity: EntityWorldMut| {
+            if let Some(then) = entity.take::<DecayThen<From, To>>() {
+                entity.insert((then.duration, then.next));
+            }
+        });
+    }
+}
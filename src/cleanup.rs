@@ -0,0 +1,40 @@
+use crate::{Decay, DecayDelayTimer, DecayDuration, DecayTimer};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Event triggered when a timer of the track `T` is found orphaned and removed.
+///
+/// A timer is orphaned once both the marker component of the track and the `DecayDuration`
+/// were removed from the entity through unusual paths, since the decay can then never resume.
+/// Observing it helps track down the code removing them.
+#[derive(Event, Debug)]
+pub struct DecayTimerOrphaned<T: Component = Decay> {
+    /// The entity the orphaned timer was removed from.
+    pub entity: Entity,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+/// Query filter for the entities with an orphaned timer of the track `T`.
+type Orphaned<T> = (
+    Or<(With<DecayTimer<T>>, With<DecayDelayTimer<T>>)>,
+    Without<T>,
+    Without<DecayDuration>,
+);
+
+/// System that removes the orphaned timers of the track `T`.
+pub(crate) fn remove_orphaned_timers<T: Component>(
+    mut commands: Commands,
+    query: Query<Entity, Orphaned<T>>,
+) {
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .remove::<(DecayTimer<T>, DecayDelayTimer<T>)>();
+
+        commands.trigger(DecayTimerOrphaned::<T> {
+            entity,
+            track: PhantomData,
+        });
+    }
+}
@@ -0,0 +1,358 @@
+#[cfg(feature = "ron")]
+use crate::snapshot;
+use crate::{
+    lod, path, pause, rate::DecaySpeed, Completion, Decay, DecayCharges, DecayCompleted,
+    DecayCompletionGuard, DecayDelay, DecayDelayTimer, DecayDuration, DecayLodWaiting,
+    DecayStackConsumed, DecayStacks, DecayTag, DecayTimer, NeverDecay,
+};
+use bevy::{
+    ecs::query::{QueryFilter, QueryItem},
+    prelude::*,
+};
+use std::{borrow::Cow, time::Duration};
+
+/// Extension trait adding decay related commands to `EntityCommands`.
+pub trait DecayEntityCommandsExt {
+    /// Consumes a charge of the `DecayCharges` of the entity, if any.
+    ///
+    /// Once the charges hit zero, the decay of the entity completes as if its timer had
+    /// finished.
+    fn consume_charge(&mut self) -> &mut Self;
+
+    /// Completes the decay of the entity immediately, skipping the wait.
+    ///
+    /// The decay completes through the normal completion path: the marker and timer are
+    /// removed, the `DecayCompleted` event is triggered and the completion actions run. Any
+    /// remaining stacks are skipped. Does nothing if the entity is not decaying.
+    fn complete_decay_now(&mut self) -> &mut Self;
+
+    /// Completes the decay of the track `T` of the entity immediately, as
+    /// [`complete_decay_now`](Self::complete_decay_now) does for the `Decay` track.
+    fn complete_track_now<T: Component>(&mut self) -> &mut Self;
+
+    /// Pauses the decay of the entity on behalf of the given source.
+    ///
+    /// The decay stays paused until every source released all of its pauses with
+    /// [`resume_decay`](Self::resume_decay).
+    fn pause_decay(&mut self, source: impl Into<Cow<'static, str>>) -> &mut Self;
+
+    /// Releases a pause of the decay of the entity held by the given source.
+    ///
+    /// The decay resumes once no source holds any pause. Releasing a source that holds no
+    /// pause does nothing.
+    fn resume_decay(&mut self, source: impl Into<Cow<'static, str>>) -> &mut Self;
+
+    /// Sets a field of the decay of the entity through a reflection path of its
+    /// [`DecayControl`](crate::DecayControl), such as `("remaining", "30")` or
+    /// `("speed", "0.5")`, logging a warning on failure.
+    ///
+    /// Meant for tools driving the decay from strings, such as a dev console or an editor.
+    fn set_decay_path(&mut self, path: impl Into<String>, value: impl Into<String>) -> &mut Self;
+}
+
+impl DecayEntityCommandsExt for EntityCommands<'_> {
+    fn consume_charge(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            if let Some(mut charges) = entity.get_mut::<DecayCharges>() {
+                charges.0 = charges.0.saturating_sub(1);
+            }
+        })
+    }
+
+    fn complete_decay_now(&mut self) -> &mut Self {
+        self.complete_track_now::<Decay>()
+    }
+
+    fn complete_track_now<T: Component>(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| complete_now::<T>(&mut entity))
+    }
+
+    fn pause_decay(&mut self, source: impl Into<Cow<'static, str>>) -> &mut Self {
+        let source = source.into();
+        self.queue(move |mut entity: EntityWorldMut| pause::pause(&mut entity, source))
+    }
+
+    fn resume_decay(&mut self, source: impl Into<Cow<'static, str>>) -> &mut Self {
+        let source = source.into();
+        self.queue(move |mut entity: EntityWorldMut| pause::resume(&mut entity, &source))
+    }
+
+    fn set_decay_path(&mut self, path: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        let (path, value) = (path.into(), value.into());
+        self.queue(move |mut entity: EntityWorldMut| {
+            if let Err(error) = path::set_decay_path::<Decay>(&mut entity, &path, &value) {
+                warn!(
+                    "could not set the decay path {path} of {}: {error}",
+                    entity.id()
+                );
+            }
+        })
+    }
+}
+
+/// Extension trait adding decay related commands to `Commands`.
+pub trait DecayCommandsExt {
+    /// Advances the decay of the entities matching the query filter `F` by the given amount,
+    /// such as every entity of an unloaded region being re-simulated.
+    ///
+    /// The amount is scaled by the decay speed of each entity, as when ticking. Stacks
+    /// finishing within the amount are consumed, and decays whose final stack finishes complete
+    /// through the same path as [`complete_decay_now`](DecayEntityCommandsExt::complete_decay_now),
+    /// all in chronological order. Paused decays are left untouched.
+    fn fast_forward_decay<F: QueryFilter + 'static>(&mut self, amount: Duration) -> &mut Self;
+
+    /// Pauses the decay of every entity matching the query filter `F` on behalf of the given
+    /// source, such as everything inside a protection zone.
+    ///
+    /// Behaves as [`pause_decay`](DecayEntityCommandsExt::pause_decay) on each entity.
+    fn pause_decay_where<F: QueryFilter + 'static>(
+        &mut self,
+        source: impl Into<Cow<'static, str>>,
+    ) -> &mut Self;
+
+    /// Releases a pause held by the given source on every entity matching the query filter
+    /// `F`.
+    ///
+    /// Behaves as [`resume_decay`](DecayEntityCommandsExt::resume_decay) on each entity.
+    fn resume_decay_where<F: QueryFilter + 'static>(
+        &mut self,
+        source: impl Into<Cow<'static, str>>,
+    ) -> &mut Self;
+
+    /// Exports every active or paused decay into a RON document, triggering the
+    /// `DecaySnapshotExported` event with it.
+    ///
+    /// Behaves as [`export_decay_snapshot`](crate::export_decay_snapshot) for the `Decay`
+    /// track, logging a warning on failure.
+    #[cfg(feature = "ron")]
+    fn export_decay_snapshot(&mut self) -> &mut Self;
+
+    /// Imports the decays of the given RON document.
+    ///
+    /// Behaves as [`import_decay_snapshot`](crate::import_decay_snapshot) for the `Decay`
+    /// track, logging a warning on failure.
+    #[cfg(feature = "ron")]
+    fn import_decay_snapshot(&mut self, document: impl Into<String>) -> &mut Self;
+}
+
+impl DecayCommandsExt for Commands<'_, '_> {
+    fn fast_forward_decay<F: QueryFilter + 'static>(&mut self, amount: Duration) -> &mut Self {
+        self.queue(move |world: &mut World| fast_forward::<Decay, F>(world, amount));
+        self
+    }
+
+    fn pause_decay_where<F: QueryFilter + 'static>(
+        &mut self,
+        source: impl Into<Cow<'static, str>>,
+    ) -> &mut Self {
+        let source = source.into();
+        self.queue(move |world: &mut World| {
+            for entity in matching::<F>(world) {
+                pause::pause(&mut world.entity_mut(entity), source.clone());
+            }
+        });
+        self
+    }
+
+    fn resume_decay_where<F: QueryFilter + 'static>(
+        &mut self,
+        source: impl Into<Cow<'static, str>>,
+    ) -> &mut Self {
+        let source = source.into();
+        self.queue(move |world: &mut World| {
+            for entity in matching::<F>(world) {
+                pause::resume(&mut world.entity_mut(entity), &source);
+            }
+        });
+        self
+    }
+
+    #[cfg(feature = "ron")]
+    fn export_decay_snapshot(&mut self) -> &mut Self {
+        self.queue(
+            |world: &mut World| match snapshot::export_decay_snapshot::<Decay>(world) {
+                Ok(document) => world.trigger(snapshot::DecaySnapshotExported { document }),
+                Err(error) => warn!("{error}"),
+            },
+        );
+        self
+    }
+
+    #[cfg(feature = "ron")]
+    fn import_decay_snapshot(&mut self, document: impl Into<String>) -> &mut Self {
+        let document = document.into();
+        self.queue(move |world: &mut World| {
+            if let Err(error) = snapshot::import_decay_snapshot::<Decay>(world, &document) {
+                warn!("{error}");
+            }
+        });
+        self
+    }
+}
+
+/// Returns the entities matching the query filter `F`.
+fn matching<F: QueryFilter>(world: &mut World) -> Vec<Entity> {
+    world.query_filtered::<Entity, F>().iter(world).collect()
+}
+
+/// Query data of the decays advanced by `fast_forward`.
+type FastForwardData<T> = (
+    Entity,
+    &'static mut DecayTimer<T>,
+    DecaySpeed,
+    Option<&'static DecayDuration>,
+    Option<&'static mut DecayStacks>,
+);
+
+/// Query filter for the decays of the track `T` advanced by `fast_forward`.
+type FastForward<T, F> = (With<T>, Without<NeverDecay>, F);
+
+/// The shortest step of a fast-forward, so float rounding at the bounds of a
+/// `DecaySpeedSchedule` cannot stall it.
+const MIN_STEP: Duration = Duration::from_millis(1);
+
+/// Advances the running decays of the track `T` of the entities matching the query filter `F`
+/// by the given span, as the ticking and completion systems would have over it.
+pub(crate) fn fast_forward<T: Component, F: QueryFilter>(world: &mut World, span: Duration) {
+    let waiting = world
+        .query_filtered::<Entity, (With<DecayLodWaiting<T>>, FastForward<T, F>)>()
+        .iter(world)
+        .collect::<Vec<_>>();
+    lod::stop_waiting_all::<T>(world, waiting);
+
+    let mut query = world.query_filtered::<FastForwardData<T>, FastForward<T, F>>();
+
+    let mut outcomes = vec![];
+    for item in query.iter_mut(world) {
+        advance(item, span, &mut outcomes);
+    }
+
+    apply_outcomes(world, outcomes);
+}
+
+/// Advances the running decay of the track `T` of the entity by the given span, as
+/// [`fast_forward`] does.
+pub(crate) fn fast_forward_entity<T: Component>(world: &mut World, entity: Entity, span: Duration) {
+    lod::stop_waiting_all::<T>(world, [entity]);
+
+    let mut query = world.query_filtered::<FastForwardData<T>, FastForward<T, ()>>();
+
+    let mut outcomes = vec![];
+    if let Ok(item) = query.get_mut(world, entity) {
+        advance(item, span, &mut outcomes);
+    }
+
+    apply_outcomes(world, outcomes);
+}
+
+/// Advances a decay by the given span, recording each consumed stack and the completion with
+/// their offset into the span.
+///
+/// The span is scaled by the decay speed, one segment of its `DecaySpeedSchedule` at a time,
+/// and the timer is left finished once the final stack finishes.
+fn advance<T: Component>(
+    (entity, mut timer, speed, decay_duration, mut stacks): QueryItem<FastForwardData<T>>,
+    span: Duration,
+    outcomes: &mut Vec<(Duration, Completion<T>)>,
+) {
+    // Paused timers do not progress.
+    if timer.paused() {
+        return;
+    }
+
+    // Apply the elapsed time held back in the timer first.
+    let pending = std::mem::take(&mut timer.pending);
+    timer.tick(pending);
+
+    let mut offset = Duration::ZERO;
+    loop {
+        if timer.finished() {
+            // If more than one stack is left, consume a stack and restart the timer instead.
+            let Some(stacks) = stacks.as_mut().filter(|stacks| stacks.0 > 1) else {
+                outcomes.push((offset, Completion::Completed(entity)));
+                return;
+            };
+            stacks.0 -= 1;
+
+            // Re-roll the duration, or reuse the previous one if it is not available.
+            let duration = decay_duration.map_or(timer.duration(), Duration::from);
+            timer.set_duration(duration);
+            timer.reset();
+
+            let event = DecayStackConsumed::new(entity, stacks.0, duration);
+            outcomes.push((offset, Completion::StackConsumed(event)));
+        }
+
+        let left = span.saturating_sub(offset);
+        if left.is_zero() {
+            return;
+        }
+
+        // Decays that do not progress never finish.
+        let fraction = timer.fraction();
+        let multiplier = speed.multiplier_at(fraction);
+        if multiplier <= 0.0 {
+            return;
+        }
+
+        // With a speed schedule, stop at the end of the current segment as well.
+        let step = match speed.next_bound(fraction) {
+            Some(bound) => timer.duration().mul_f32(bound - fraction),
+            None => timer.remaining(),
+        };
+        let step = step.max(MIN_STEP).min(timer.remaining());
+
+        let real = step.div_f32(multiplier);
+        if real < left {
+            timer.tick(step);
+            offset += real;
+        } else {
+            timer.tick(left.mul_f32(multiplier));
+            offset = span;
+        }
+    }
+}
+
+/// Triggers the consumed stacks and completes the finished decays of the track `T`, in the
+/// order they would have happened.
+fn apply_outcomes<T: Component>(world: &mut World, mut outcomes: Vec<(Duration, Completion<T>)>) {
+    outcomes.sort_by_key(|(offset, outcome)| (*offset, outcome.entity()));
+
+    for (_, outcome) in outcomes {
+        match outcome {
+            Completion::Completed(entity) => {
+                if let Ok(mut entity) = world.get_entity_mut(entity) {
+                    complete_now::<T>(&mut entity);
+                }
+            }
+            Completion::StackConsumed(event) => world.trigger(event),
+        }
+    }
+}
+
+/// Completes the decay of the track `T` of the entity immediately, through the normal
+/// completion path.
+fn complete_now<T: Component>(entity: &mut EntityWorldMut) {
+    if !entity.contains::<T>() {
+        return;
+    }
+
+    // Guard the completion, so removing the marker does not pause the decay.
+    let id = entity.id();
+    let completed = entity.world_scope(|world| {
+        world
+            .get_resource_mut::<DecayCompletionGuard<T>>()
+            .is_some_and(|mut guard| guard.0.insert(id))
+    });
+
+    // If the entity has already completed this frame, do not complete it twice.
+    if !completed {
+        return;
+    }
+
+    let tag = entity.get::<DecayTag>().copied();
+    entity.remove::<(T, DecayTimer<T>, DecayDelay, DecayDelayTimer<T>)>();
+    entity.world_scope(|world| {
+        world.trigger(DecayCompleted::<T>::new(vec![id]).with_tags(vec![tag]));
+    });
+}
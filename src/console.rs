@@ -0,0 +1,131 @@
+use crate::{Decay, DecayEntityCommandsExt, DecayFrozen, DecayTimer, DurationFormat};
+use bevy::prelude::*;
+use bevy_console::{reply, AddConsoleCommand, ConsoleCommand};
+use clap::{Parser, Subcommand};
+
+/// Plugin registering the `decay` command of `bevy_console`, for live debugging of the
+/// `Decay` track on a running game or server.
+///
+/// - `decay list` lists the decaying entities with their remaining time.
+/// - `decay force <entity>` completes the decay of the entity immediately.
+/// - `decay pause <entity>` and `decay resume <entity>` pause and resume the decay of the
+///   entity, as the `"console"` pause source.
+/// - `decay stats` reports how many entities are decaying, paused and frozen.
+///
+/// Entities are written as displayed by Bevy, such as `12v1`.
+pub struct DecayConsolePlugin;
+
+impl Plugin for DecayConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_console_command::<DecayConsoleCommand, _>(decay_console_command);
+    }
+}
+
+/// The `decay` console command.
+#[derive(Parser, ConsoleCommand)]
+#[command(name = "decay")]
+struct DecayConsoleCommand {
+    /// The action to perform.
+    #[command(subcommand)]
+    action: DecayConsoleAction,
+}
+
+/// Actions of the `decay` console command.
+#[derive(Subcommand)]
+enum DecayConsoleAction {
+    /// Lists the decaying entities with their remaining time.
+    List,
+    /// Completes the decay of an entity immediately.
+    Force {
+        /// The entity, such as `12v1`.
+        entity: String,
+    },
+    /// Pauses the decay of an entity.
+    Pause {
+        /// The entity, such as `12v1`.
+        entity: String,
+    },
+    /// Resumes the decay of an entity paused from the console.
+    Resume {
+        /// The entity, such as `12v1`.
+        entity: String,
+    },
+    /// Reports how many entities are decaying, paused and frozen.
+    Stats,
+}
+
+/// The pause source of the console.
+const CONSOLE_SOURCE: &str = "console";
+
+/// System that executes the `decay` console command.
+fn decay_console_command(
+    mut log: ConsoleCommand<DecayConsoleCommand>,
+    mut commands: Commands,
+    timers: Query<(Entity, &DecayTimer), With<Decay>>,
+    frozen: Query<(), (With<Decay>, With<DecayFrozen>)>,
+) {
+    let Some(Ok(DecayConsoleCommand { action })) = log.take() else {
+        return;
+    };
+
+    let format = DurationFormat::default();
+    match action {
+        DecayConsoleAction::List => {
+            for (entity, timer) in timers.iter() {
+                let state = if timer.paused() { " (paused)" } else { "" };
+                reply!(log, "{entity}: {}{state}", format.format(timer.remaining()));
+            }
+        }
+        DecayConsoleAction::Force { entity } => {
+            let Some(target) = parse_entity(&entity).filter(|&e| timers.contains(e)) else {
+                reply!(log, "{entity} is not decaying");
+                return log.failed();
+            };
+
+            commands.entity(target).complete_decay_now();
+        }
+        DecayConsoleAction::Pause { entity } => {
+            let Some(mut target) = parse_entity(&entity).and_then(|e| commands.get_entity(e))
+            else {
+                reply!(log, "{entity} does not exist");
+                return log.failed();
+            };
+
+            target.pause_decay(CONSOLE_SOURCE);
+        }
+        DecayConsoleAction::Resume { entity } => {
+            let Some(mut target) = parse_entity(&entity).and_then(|e| commands.get_entity(e))
+            else {
+                reply!(log, "{entity} does not exist");
+                return log.failed();
+            };
+
+            target.resume_decay(CONSOLE_SOURCE);
+        }
+        DecayConsoleAction::Stats => {
+            let decaying = timers.iter().count();
+            let paused = timers.iter().filter(|(_, timer)| timer.paused()).count();
+            reply!(
+                log,
+                "decaying: {decaying}, paused: {paused}, frozen: {}",
+                frozen.iter().count()
+            );
+        }
+    }
+
+    log.ok();
+}
+
+/// Parses an entity as displayed by Bevy, such as `12v1`, or as its raw bits.
+fn parse_entity(text: &str) -> Option<Entity> {
+    let bits = match text.split_once('v') {
+        Some((index, generation)) => {
+            let index: u32 = index.parse().ok()?;
+            let generation: u32 = generation.parse().ok()?;
+            (u64::from(generation) << 32) | u64::from(index)
+        }
+        None => text.parse().ok()?,
+    };
+
+    Entity::try_from_bits(bits).ok()
+}
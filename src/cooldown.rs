@@ -0,0 +1,81 @@
+use crate::{Decay, DecayDuration, DecayStartOffset, DecayTimer};
+use bevy::prelude::*;
+use leafwing_abilities::cooldown::Cooldown;
+use std::time::Duration;
+
+/// Creates a `Cooldown` counting down the same remaining time as the decay timer.
+impl<T: Component> From<&DecayTimer<T>> for Cooldown {
+    fn from(timer: &DecayTimer<T>) -> Self {
+        let mut cooldown = Cooldown::new(timer.duration());
+        cooldown.set_elapsed(timer.elapsed());
+        cooldown
+    }
+}
+
+impl Decay {
+    /// Creates a ready-to-spawn bundle decaying over the max time of the `Cooldown`, starting
+    /// with its elapsed time, so the decay counts down the same remaining time.
+    ///
+    /// The decay starts through the plugin as usual, triggering `DecayStarted`.
+    pub fn for_cooldown(cooldown: &Cooldown) -> (Self, DecayDuration, DecayStartOffset) {
+        (
+            Self,
+            DecayDuration::new(cooldown.max_time()),
+            DecayStartOffset::Elapsed(cooldown.elapsed()),
+        )
+    }
+}
+
+impl<T: Component> DecayTimer<T> {
+    /// Copies the duration and elapsed time of the decay into the `Cooldown`, so an ability
+    /// cooldown can be backed by the decay timer.
+    pub fn sync_cooldown(&self, cooldown: &mut Cooldown) {
+        cooldown.set_max_time(self.duration());
+        cooldown.set_elapsed(self.elapsed());
+    }
+}
+
+/// Extension trait adding `leafwing_abilities` cooldown commands to `EntityCommands`.
+pub trait DecayCooldownCommandsExt {
+    /// Copies the max time and elapsed time of the `Cooldown` into the decay of the entity, so
+    /// the decay can be backed by an ability cooldown.
+    ///
+    /// The timer is only restarted if it differs from the cooldown, so syncing every frame
+    /// does not disturb a decay that already matches. Does nothing if the entity is not
+    /// decaying.
+    fn sync_decay_from_cooldown(&mut self, cooldown: &Cooldown) -> &mut Self;
+
+    /// Copies the `Cooldown` into the decay of the track `T` of the entity, as
+    /// [`sync_decay_from_cooldown`](Self::sync_decay_from_cooldown) does for the `Decay` track.
+    fn sync_track_from_cooldown<T: Component>(&mut self, cooldown: &Cooldown) -> &mut Self;
+}
+
+impl DecayCooldownCommandsExt for EntityCommands<'_> {
+    fn sync_decay_from_cooldown(&mut self, cooldown: &Cooldown) -> &mut Self {
+        self.sync_track_from_cooldown::<Decay>(cooldown)
+    }
+
+    fn sync_track_from_cooldown<T: Component>(&mut self, cooldown: &Cooldown) -> &mut Self {
+        let (max_time, elapsed) = (cooldown.max_time(), cooldown.elapsed());
+        self.queue(move |mut entity: EntityWorldMut| {
+            if let Some(mut timer) = entity.get_mut::<DecayTimer<T>>() {
+                sync_from_cooldown(&mut timer, max_time, elapsed);
+            }
+        })
+    }
+}
+
+/// Restarts the decay timer with the max time and elapsed time of a cooldown, if it differs.
+fn sync_from_cooldown<T: Component>(
+    timer: &mut DecayTimer<T>,
+    max_time: Duration,
+    elapsed: Duration,
+) {
+    if timer.duration() != max_time {
+        timer.set_duration(max_time);
+    } else if timer.elapsed() == elapsed {
+        return;
+    }
+
+    timer.restart_at(elapsed);
+}
@@ -0,0 +1,87 @@
+use crate::{
+    is_finished, Decay, DecayBarriers, DecayCharges, DecayCompletionBudget, DecayDuration,
+    DecayFrozen, DecayScan, DecayTimer, DecayingSet, NeverDecay,
+};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Plugin asserting the invariants of the track `T` (`Decay` by default) every frame, in debug
+/// builds only, to catch integration bugs early.
+///
+/// - Every `DecayTimer` comes with a `DecayDuration`.
+/// - Timers of paused entities, whose marker was removed or that are frozen, do not run.
+/// - Finished timers complete during the frame, unless a completion budget or background scan
+///   defers them, or a group barrier holds them.
+///
+/// Use `DecayDebugPlugin::default()` for the `Decay` track and `DecayDebugPlugin::<T>::new()`
+/// for any other track.
+pub struct DecayDebugPlugin<T: Component = Decay> {
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+// `Default` is only implemented for the `Decay` track, so `DecayDebugPlugin::default()` can be
+// inferred without naming the track.
+#[allow(clippy::new_without_default)]
+impl<T: Component> DecayDebugPlugin<T> {
+    /// Creates a new `DecayDebugPlugin` for the decay track of the marker component `T`.
+    pub fn new() -> Self {
+        Self { track: PhantomData }
+    }
+}
+
+impl Default for DecayDebugPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Component> Plugin for DecayDebugPlugin<T> {
+    fn build(&self, app: &mut App) {
+        if cfg!(debug_assertions) {
+            app.add_systems(
+                PreUpdate,
+                check_decay_invariants::<T>.after(DecayingSet::Complete),
+            );
+        }
+    }
+}
+
+/// System that asserts the invariants of the track `T`.
+fn check_decay_invariants<T: Component>(
+    budget: Option<Res<DecayCompletionBudget<T>>>,
+    scan: Option<Res<DecayScan<T>>>,
+    barriers: Option<Res<DecayBarriers<T>>>,
+    timers: Query<(
+        Entity,
+        &DecayTimer<T>,
+        Option<&DecayCharges>,
+        Has<DecayDuration>,
+        Has<T>,
+        Has<DecayFrozen>,
+        Has<NeverDecay>,
+    )>,
+) {
+    // Deferred completions legitimately leave finished timers behind.
+    let deferred = budget.is_some() || scan.is_some();
+
+    for (entity, timer, charges, duration, marker, frozen, never) in timers.iter() {
+        debug_assert!(
+            duration,
+            "{entity} has a decay timer without a `DecayDuration`"
+        );
+
+        debug_assert!(
+            (marker && !frozen) || timer.paused(),
+            "{entity} has a running decay timer while its decay is paused"
+        );
+
+        let held = barriers
+            .as_ref()
+            .is_some_and(|barriers| barriers.held.contains(&entity));
+        debug_assert!(
+            !marker || never || deferred || held || !is_finished(timer, charges),
+            "{entity} has a finished decay timer that did not complete"
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{Decay, DecayDuration, DecayTimer};
+use bevy::{
+    asset::{io::Reader, AssetLoader, LoadContext},
+    prelude::*,
+    utils::HashSet,
+};
+use serde::Deserialize;
+use std::{error::Error, fmt, marker::PhantomData, time::Duration};
+
+/// Plugin loading `DecayDefinition` assets and applying them to the entities referencing them
+/// for the track `T` (`Decay` by default), including when they change at runtime.
+///
+/// Use `DecayDefinitionPlugin::default()` for the `Decay` track and
+/// `DecayDefinitionPlugin::<T>::new()` for any other track. The running decays are updated
+/// according to the `DecayReloadPolicy` resource, which is initialized with its default value
+/// unless inserted beforehand.
+pub struct DecayDefinitionPlugin<T: Component = Decay> {
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+// `Default` is only implemented for the `Decay` track, so `DecayDefinitionPlugin::default()`
+// can be inferred without naming the track.
+#[allow(clippy::new_without_default)]
+impl<T: Component> DecayDefinitionPlugin<T> {
+    /// Creates a new `DecayDefinitionPlugin` for the decay track of the marker component `T`.
+    pub fn new() -> Self {
+        Self { track: PhantomData }
+    }
+}
+
+impl Default for DecayDefinitionPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Component> Plugin for DecayDefinitionPlugin<T> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<DecayDefinitionAssetPlugin>() {
+            app.add_plugins(DecayDefinitionAssetPlugin);
+        }
+
+        app.add_systems(PreUpdate, apply_decay_definitions::<T>);
+    }
+}
+
+/// Plugin registering the `DecayDefinition` asset, shared by every track.
+struct DecayDefinitionAssetPlugin;
+
+impl Plugin for DecayDefinitionAssetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<DecayDefinition>()
+            .init_asset_loader::<DecayDefinitionLoader>()
+            .init_resource::<DecayReloadPolicy>();
+    }
+}
+
+/// Asset defining the duration range of a decay, loaded from `.decay.ron` files such as
+/// `(min: 60.0, max: 90.0)`, in seconds.
+#[derive(Asset, TypePath, Deserialize, Clone, Copy, Debug)]
+pub struct DecayDefinition {
+    /// The minimum duration for decay, in seconds.
+    pub min: f32,
+    /// The maximum duration for decay, in seconds.
+    pub max: f32,
+}
+
+impl DecayDefinition {
+    /// Returns the duration range of the definition.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a bound is infinite, which the asset loader rejects.
+    pub fn duration(&self) -> DecayDuration {
+        DecayDuration::randomized(
+            Duration::from_secs_f32(self.min.max(0.0)),
+            Duration::from_secs_f32(self.max.max(0.0)),
+        )
+    }
+}
+
+/// Component referencing the `DecayDefinition` the `DecayDuration` of an entity comes from.
+///
+/// The duration is inserted once the definition is loaded, and updated whenever it changes.
+/// Without the `require-duration` feature, the decay starts once the duration is inserted.
+#[derive(Component, Clone, Debug)]
+pub struct DecayDefinitionHandle(pub Handle<DecayDefinition>);
+
+/// Policy applied to the running decays when their `DecayDefinition` changes.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum DecayReloadPolicy {
+    /// Rolls a new duration and keeps the fraction of the decay that has elapsed.
+    #[default]
+    Rescale,
+    /// Restarts the decay with a new duration.
+    Restart,
+    /// Leaves the running decays untouched; only new decays use the new duration.
+    Ignore,
+}
+
+/// Loader of `DecayDefinition` assets from RON files.
+#[derive(Default)]
+struct DecayDefinitionLoader;
+
+impl AssetLoader for DecayDefinitionLoader {
+    type Asset = DecayDefinition;
+    type Settings = ();
+    type Error = DecayDefinitionLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let definition: DecayDefinition = ron::de::from_bytes(&bytes)?;
+
+        // Reject the bounds that cannot be turned into a duration.
+        for secs in [definition.min, definition.max] {
+            Duration::try_from_secs_f32(secs)
+                .map_err(|_| DecayDefinitionLoaderError::InvalidDuration(secs))?;
+        }
+
+        Ok(definition)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["decay.ron"]
+    }
+}
+
+/// Error raised while loading a `DecayDefinition`.
+#[derive(Debug)]
+pub enum DecayDefinitionLoaderError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// The file is not a valid RON definition.
+    Ron(ron::error::SpannedError),
+    /// A bound of the definition is negative, infinite or NaN.
+    InvalidDuration(f32),
+}
+
+impl fmt::Display for DecayDefinitionLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read the decay definition: {error}"),
+            Self::Ron(error) => write!(f, "could not parse the decay definition: {error}"),
+            Self::InvalidDuration(secs) => {
+                write!(f, "invalid decay definition duration: {secs} seconds")
+            }
+        }
+    }
+}
+
+impl Error for DecayDefinitionLoaderError {}
+
+impl From<std::io::Error> for DecayDefinitionLoaderError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<ron::error::SpannedError> for DecayDefinitionLoaderError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        Self::Ron(error)
+    }
+}
+
+/// System that applies loaded and changed definitions to the entities referencing them.
+fn apply_decay_definitions<T: Component>(
+    mut commands: Commands,
+    mut events: EventReader<AssetEvent<DecayDefinition>>,
+    definitions: Res<Assets<DecayDefinition>>,
+    policy: Res<DecayReloadPolicy>,
+    mut query: Query<(
+        Entity,
+        Ref<DecayDefinitionHandle>,
+        Option<&mut DecayTimer<T>>,
+    )>,
+) {
+    let mut loaded = HashSet::new();
+    let mut modified = HashSet::new();
+    for event in events.read() {
+        match *event {
+            AssetEvent::LoadedWithDependencies { id } => {
+                loaded.insert(id);
+            }
+            AssetEvent::Modified { id } => {
+                modified.insert(id);
+            }
+            _ => {}
+        }
+    }
+
+    for (entity, handle, timer) in query.iter_mut() {
+        let id = handle.0.id();
+        let changed = modified.contains(&id);
+
+        // Only apply the definitions that were just loaded, changed or referenced.
+        if !changed && !loaded.contains(&id) && !handle.is_added() {
+            continue;
+        }
+
+        let Some(definition) = definitions.get(id) else {
+            continue;
+        };
+
+        let duration = definition.duration();
+        commands.entity(entity).insert(duration);
+
+        // Update the running decay according to the policy.
+        let Some(mut timer) = timer.filter(|_| changed) else {
+            continue;
+        };
+
+        match *policy {
+            DecayReloadPolicy::Rescale => {
+                let fraction = timer.fraction();
+                let new_duration = Duration::from(&duration);
+                timer.set_duration(new_duration);
+                timer.restart_at(new_duration.mul_f32(fraction));
+            }
+            DecayReloadPolicy::Restart => {
+                timer.set_duration(Duration::from(&duration));
+                timer.restart_at(Duration::ZERO);
+            }
+            DecayReloadPolicy::Ignore => {}
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::{
+    ecs::entity::{Entities, EntityHashSet},
+    prelude::*,
+};
+use std::collections::VecDeque;
+
+/// Resource batching the despawns of the `DecayAction::Despawn` completions.
+///
+/// When this resource is present, entities completing with `DecayAction::Despawn` are queued
+/// instead of despawned right away, and at most `max_per_frame` of them are despawned each
+/// frame, so big completion waves do not stall a single frame. Without it, they are despawned
+/// recursively as soon as they complete.
+#[derive(Resource, Debug)]
+pub struct DecayDespawner {
+    /// Whether the children of the entities are despawned along with them, rather than being
+    /// detached and left in the world.
+    pub recursive: bool,
+    /// The maximum number of entities despawned each frame, if limited.
+    pub max_per_frame: Option<usize>,
+    /// Whether a `DecayDespawning` event is triggered with the entities about to be despawned.
+    pub pre_despawn_event: bool,
+    /// The entities waiting to be despawned, from the first completed.
+    queue: VecDeque<Entity>,
+}
+
+impl DecayDespawner {
+    /// Creates a new `DecayDespawner` despawning the entities recursively, without limit.
+    pub fn new() -> Self {
+        Self {
+            recursive: true,
+            max_per_frame: None,
+            pre_despawn_event: false,
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// Despawns only the entities themselves, detaching their children instead.
+    pub fn flat(mut self) -> Self {
+        self.recursive = false;
+        self
+    }
+
+    /// Despawns at most the given number of entities each frame, deferring the others.
+    pub fn with_max_per_frame(mut self, max_per_frame: usize) -> Self {
+        self.max_per_frame = Some(max_per_frame);
+        self
+    }
+
+    /// Triggers a `DecayDespawning` event with the entities about to be despawned each frame.
+    pub fn with_pre_despawn_event(mut self) -> Self {
+        self.pre_despawn_event = true;
+        self
+    }
+
+    /// Returns the number of entities waiting to be despawned.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Checks if no entity is waiting to be despawned.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Queues the entity to be despawned.
+    pub(crate) fn push(&mut self, entity: Entity) {
+        self.queue.push_back(entity);
+    }
+}
+
+impl Default for DecayDespawner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Event triggered by the `DecayDespawner` with the entities about to be despawned, while they
+/// can still be inspected.
+#[derive(Event, Clone, Debug)]
+pub struct DecayDespawning {
+    /// The entities about to be despawned.
+    pub entities: Vec<Entity>,
+}
+
+/// System that despawns the entities queued in the `DecayDespawner`, within its budget.
+pub(crate) fn despawn_decayed(
+    mut commands: Commands,
+    mut despawner: ResMut<DecayDespawner>,
+    entities: &Entities,
+) {
+    let count = despawner
+        .max_per_frame
+        .map_or(despawner.queue.len(), |max| max.min(despawner.queue.len()));
+
+    // Entities despawned by other means since their completion, or queued by several tracks,
+    // are skipped.
+    let mut seen = EntityHashSet::default();
+    let despawned: Vec<_> = despawner
+        .queue
+        .drain(..count)
+        .filter(|entity| entities.contains(*entity) && seen.insert(*entity))
+        .collect();
+
+    if despawned.is_empty() {
+        return;
+    }
+
+    if despawner.pre_despawn_event {
+        commands.trigger(DecayDespawning {
+            entities: despawned.clone(),
+        });
+    }
+
+    for entity in despawned {
+        if despawner.recursive {
+            commands.entity(entity).despawn_recursive();
+        } else {
+            commands.entity(entity).clear_children().despawn();
+        }
+    }
+}
@@ -0,0 +1,413 @@
+use crate::{Decay, DecayCompleted, DecayCompletionGuard, DecayTimer};
+use bevy::{
+    ecs::{
+        archetype::{ArchetypeId, Archetypes},
+        component::Components,
+        entity::{Entities, EntityHashMap},
+    },
+    prelude::*,
+    utils::{HashMap, Instant},
+};
+use std::{marker::PhantomData, time::Duration};
+
+/// Histogram of the upcoming decay expirations of the track `T`, bucketed by remaining time.
+///
+/// Each bucket counts the running decays whose remaining time is below its upper bound and
+/// above the bound of the previous bucket, with a final bucket for everything beyond the last
+/// bound. It is updated every frame and helps detect completion spikes before they happen.
+#[derive(Resource, Debug)]
+pub struct DecayExpirationHistogram<T: Component = Decay> {
+    /// The upper bounds of the buckets, in ascending order.
+    bounds: Vec<Duration>,
+    /// The number of decays in each bucket, with one extra bucket beyond the last bound.
+    counts: Vec<usize>,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayExpirationHistogram<T> {
+    /// Creates a new `DecayExpirationHistogram` with the given bucket bounds.
+    ///
+    /// The bounds are sorted and deduplicated.
+    pub fn new(mut bounds: Vec<Duration>) -> Self {
+        bounds.sort();
+        bounds.dedup();
+
+        Self {
+            counts: vec![0; bounds.len() + 1],
+            bounds,
+            track: PhantomData,
+        }
+    }
+
+    /// Returns the upper bounds of the buckets.
+    pub fn bounds(&self) -> &[Duration] {
+        &self.bounds
+    }
+
+    /// Returns the number of decays in each bucket, the last one being beyond the last bound.
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// Iterates over the buckets as pairs of upper bound (`None` for the last bucket) and count.
+    pub fn iter(&self) -> impl Iterator<Item = (Option<Duration>, usize)> + '_ {
+        self.bounds
+            .iter()
+            .copied()
+            .map(Some)
+            .chain([None])
+            .zip(self.counts.iter().copied())
+    }
+
+    /// Records a decay with the given remaining time.
+    fn record(&mut self, remaining: Duration) {
+        let bucket = self.bounds.partition_point(|bound| *bound < remaining);
+        self.counts[bucket] += 1;
+    }
+}
+
+/// Buckets of the next second, 10 seconds, minute, 10 minutes and hour.
+impl<T: Component> Default for DecayExpirationHistogram<T> {
+    fn default() -> Self {
+        Self::new(vec![
+            Duration::from_secs(1),
+            Duration::from_secs(10),
+            Duration::from_secs(60),
+            Duration::from_secs(10 * 60),
+            Duration::from_secs(60 * 60),
+        ])
+    }
+}
+
+/// System that updates the expiration histogram of the track `T` with the running decays.
+pub(crate) fn update_expiration_histogram<T: Component>(
+    mut histogram: ResMut<DecayExpirationHistogram<T>>,
+    query: Query<&DecayTimer<T>, With<T>>,
+) {
+    histogram.counts.fill(0);
+
+    for timer in query.iter() {
+        // Paused decays are not about to expire.
+        if !timer.paused() {
+            histogram.record(timer.remaining());
+        }
+    }
+}
+
+/// Histogram of how late the decays of the track `T` completed relative to their deadline.
+///
+/// Timers only finish when they are ticked, so completions fire up to a frame (or a scan
+/// interval or deferred budget) after their exact deadline. Each completion is counted in the
+/// bucket of its overshoot, with a final bucket for everything beyond the last bound, which
+/// helps decide whether a fixed timestep or coarser ticks meet the required accuracy. Unlike
+/// the `DecayExpirationHistogram`, the counts accumulate until [`clear`](Self::clear) is
+/// called.
+///
+/// The deadline is estimated when the timer finishes, from the part of the tick beyond the
+/// remaining time, scaled back to real time by the current decay speed.
+#[derive(Resource, Debug)]
+pub struct DecayOvershootHistogram<T: Component = Decay> {
+    /// The upper bounds of the buckets, in ascending order.
+    bounds: Vec<Duration>,
+    /// The number of completions in each bucket, with one extra bucket beyond the last bound.
+    counts: Vec<usize>,
+    /// The sum of the recorded overshoots.
+    total: Duration,
+    /// The largest recorded overshoot.
+    max: Duration,
+    /// The estimated deadlines of the finished decays waiting for their completion.
+    deadlines: EntityHashMap<Duration>,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayOvershootHistogram<T> {
+    /// Creates a new `DecayOvershootHistogram` with the given bucket bounds.
+    ///
+    /// The bounds are sorted and deduplicated.
+    pub fn new(mut bounds: Vec<Duration>) -> Self {
+        bounds.sort();
+        bounds.dedup();
+
+        Self {
+            counts: vec![0; bounds.len() + 1],
+            bounds,
+            total: Duration::ZERO,
+            max: Duration::ZERO,
+            deadlines: EntityHashMap::default(),
+            track: PhantomData,
+        }
+    }
+
+    /// Returns the upper bounds of the buckets.
+    pub fn bounds(&self) -> &[Duration] {
+        &self.bounds
+    }
+
+    /// Returns the number of completions in each bucket, the last one being beyond the last
+    /// bound.
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// Iterates over the buckets as pairs of upper bound (`None` for the last bucket) and count.
+    pub fn iter(&self) -> impl Iterator<Item = (Option<Duration>, usize)> + '_ {
+        self.bounds
+            .iter()
+            .copied()
+            .map(Some)
+            .chain([None])
+            .zip(self.counts.iter().copied())
+    }
+
+    /// Returns the number of recorded completions.
+    pub fn len(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    /// Returns whether no completion was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the mean overshoot of the recorded completions.
+    pub fn mean(&self) -> Duration {
+        match u32::try_from(self.len()) {
+            Ok(0) => Duration::ZERO,
+            Ok(len) => self.total / len,
+            Err(_) => self.total.div_f64(self.len() as f64),
+        }
+    }
+
+    /// Returns the largest overshoot of the recorded completions.
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Clears the recorded completions, e.g. to measure a new sampling window.
+    pub fn clear(&mut self) {
+        self.counts.fill(0);
+        self.total = Duration::ZERO;
+        self.max = Duration::ZERO;
+    }
+
+    /// Records the estimated deadline of a decay whose timer just finished.
+    pub(crate) fn record_deadline(&mut self, entity: Entity, deadline: Duration) {
+        self.deadlines.insert(entity, deadline);
+    }
+
+    /// Records a completion with the given overshoot.
+    fn record(&mut self, overshoot: Duration) {
+        let bucket = self.bounds.partition_point(|bound| *bound < overshoot);
+        self.counts[bucket] += 1;
+        self.total += overshoot;
+        self.max = self.max.max(overshoot);
+    }
+}
+
+/// Buckets of a millisecond, 5 milliseconds, a frame at 60 and 30 FPS, 100 milliseconds and a
+/// second.
+impl<T: Component> Default for DecayOvershootHistogram<T> {
+    fn default() -> Self {
+        Self::new(vec![
+            Duration::from_millis(1),
+            Duration::from_millis(5),
+            Duration::from_micros(16_667),
+            Duration::from_micros(33_333),
+            Duration::from_millis(100),
+            Duration::from_secs(1),
+        ])
+    }
+}
+
+/// System that records the overshoot of the completed decays of the track `T`.
+pub(crate) fn record_completion_overshoot<T: Component>(
+    trigger: Trigger<DecayCompleted<T>>,
+    time: Res<Time>,
+    mut histogram: ResMut<DecayOvershootHistogram<T>>,
+) {
+    let now = time.elapsed();
+
+    for entity in trigger.iter() {
+        // Decays finished by other means, such as exhausted charges, have no deadline.
+        let Some(deadline) = histogram.deadlines.remove(entity) else {
+            continue;
+        };
+
+        let overshoot = now.saturating_sub(deadline);
+        histogram.record(overshoot);
+
+        #[cfg(feature = "metrics")]
+        crate::telemetry::record_overshoot::<T>(overshoot);
+    }
+}
+
+/// System that drops the estimated deadline of a decay of the track `T` whose timer was removed
+/// without completing, such as when it was cancelled or the entity despawned.
+pub(crate) fn prune_overshoot_deadline<T: Component>(
+    trigger: Trigger<OnRemove, DecayTimer<T>>,
+    guard: Res<DecayCompletionGuard<T>>,
+    mut histogram: ResMut<DecayOvershootHistogram<T>>,
+) {
+    let entity = trigger.entity();
+
+    // The deadline of completed decays is recorded by the completion observer.
+    if !guard.contains(entity) {
+        histogram.deadlines.remove(&entity);
+    }
+}
+
+/// Metrics of the track `T`, reporting how many entities decay in each archetype and how long
+/// ticking the track, and each of its archetypes, took.
+///
+/// It is updated every frame and helps find which item categories dominate the decay budget.
+#[derive(Resource, Debug)]
+pub struct DecayTrackMetrics<T: Component = Decay> {
+    /// The number of entities decaying on the track.
+    entities: usize,
+    /// The time spent ticking the track during the last frame.
+    tick_time: Duration,
+    /// The decaying entities of each archetype.
+    archetypes: Vec<DecayArchetypeMetrics>,
+    /// The time spent ticking each archetype during the last frame.
+    archetype_tick_times: HashMap<ArchetypeId, Duration>,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> Default for DecayTrackMetrics<T> {
+    fn default() -> Self {
+        Self {
+            entities: 0,
+            tick_time: Duration::ZERO,
+            archetypes: Vec::new(),
+            archetype_tick_times: HashMap::new(),
+            track: PhantomData,
+        }
+    }
+}
+
+impl<T: Component> DecayTrackMetrics<T> {
+    /// Returns the number of entities decaying on the track.
+    pub fn entities(&self) -> usize {
+        self.entities
+    }
+
+    /// Returns the time spent ticking the track during the last frame.
+    pub fn tick_time(&self) -> Duration {
+        self.tick_time
+    }
+
+    /// Returns the decaying entities of each archetype, from the most to the least populated.
+    pub fn archetypes(&self) -> &[DecayArchetypeMetrics] {
+        &self.archetypes
+    }
+
+    /// Records the time spent ticking the track, and each of its archetypes.
+    pub(crate) fn record_tick(
+        &mut self,
+        tick_time: Duration,
+        archetype_tick_times: HashMap<ArchetypeId, Duration>,
+    ) {
+        self.tick_time = tick_time;
+        self.archetype_tick_times = archetype_tick_times;
+    }
+}
+
+/// Decaying entities of a single archetype, as reported by `DecayTrackMetrics`.
+#[derive(Clone, Debug)]
+pub struct DecayArchetypeMetrics {
+    /// The archetype of the entities.
+    pub archetype: ArchetypeId,
+    /// The names of the components of the archetype.
+    pub components: Vec<String>,
+    /// The number of entities of the archetype decaying on the track.
+    pub entities: usize,
+    /// The time spent ticking the entities of the archetype during the last frame.
+    pub tick_time: Duration,
+}
+
+/// Stopwatch attributing the time spent ticking a track to the archetypes of the ticked
+/// entities.
+///
+/// The entities are mostly visited archetype by archetype, so the clock is only read when the
+/// archetype changes from one entity to the next.
+#[derive(Default)]
+pub(crate) struct ArchetypeStopwatch {
+    /// The archetype being ticked, and since when.
+    current: Option<(ArchetypeId, Instant)>,
+    /// The time spent ticking each archetype.
+    times: HashMap<ArchetypeId, Duration>,
+}
+
+impl ArchetypeStopwatch {
+    /// Attributes the time from now on to the archetype of the entity.
+    pub(crate) fn enter(&mut self, entities: &Entities, entity: Entity) {
+        let Some(location) = entities.get(entity) else {
+            return;
+        };
+        if matches!(self.current, Some((archetype, _)) if archetype == location.archetype_id) {
+            return;
+        }
+
+        let now = Instant::now();
+        self.stop(now);
+        self.current = Some((location.archetype_id, now));
+    }
+
+    /// Stops the stopwatch, returning the time spent ticking each archetype.
+    pub(crate) fn finish(mut self) -> HashMap<ArchetypeId, Duration> {
+        self.stop(Instant::now());
+        self.times
+    }
+
+    /// Attributes the time until `now` to the archetype being ticked.
+    fn stop(&mut self, now: Instant) {
+        if let Some((archetype, since)) = self.current.take() {
+            *self.times.entry(archetype).or_default() += now - since;
+        }
+    }
+}
+
+/// System that updates the metrics of the track `T` with the decaying entities of each
+/// archetype.
+pub(crate) fn update_track_metrics<T: Component>(
+    mut metrics: ResMut<DecayTrackMetrics<T>>,
+    entities: &Entities,
+    archetypes: &Archetypes,
+    components: &Components,
+    query: Query<Entity, (With<T>, With<DecayTimer<T>>)>,
+) {
+    let mut counts: HashMap<ArchetypeId, usize> = HashMap::new();
+    for entity in query.iter() {
+        if let Some(location) = entities.get(entity) {
+            *counts.entry(location.archetype_id).or_default() += 1;
+        }
+    }
+
+    metrics.entities = counts.values().sum();
+    metrics.archetypes = counts
+        .into_iter()
+        .map(|(archetype, entities)| DecayArchetypeMetrics {
+            archetype,
+            components: archetypes
+                .get(archetype)
+                .into_iter()
+                .flat_map(|archetype| archetype.components())
+                .filter_map(|id| components.get_name(id))
+                .map(ToString::to_string)
+                .collect(),
+            entities,
+            tick_time: metrics
+                .archetype_tick_times
+                .get(&archetype)
+                .copied()
+                .unwrap_or_default(),
+        })
+        .collect();
+
+    metrics
+        .archetypes
+        .sort_by(|a, b| b.entities.cmp(&a.entities));
+}
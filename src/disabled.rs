@@ -0,0 +1,101 @@
+use crate::{commands, Decay};
+use bevy::prelude::*;
+use std::{marker::PhantomData, time::Duration};
+
+/// How the decay of a track behaves while its entity is disabled, as configured per track with
+/// [`DecayPlugin::with_disabling`](crate::DecayPlugin::with_disabling).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub enum DecayWhileDisabled {
+    /// The decay keeps ticking, as if the entity were enabled.
+    KeepTicking,
+    /// The decay is skipped, and the span spent disabled is lost.
+    Skip,
+    /// The decay is skipped, and the span spent disabled is applied at once when the entity is
+    /// enabled again, completing the decay if it ran out meanwhile.
+    #[default]
+    CatchUp,
+}
+
+/// Component marking the entities whose decay of the track `T` is skipped while they are
+/// disabled.
+///
+/// It is managed by the plugin, and only public so the disabled entities can be queried or
+/// excluded.
+#[derive(Component, Debug)]
+pub struct DecayDisabled<T: Component = Decay> {
+    /// The elapsed virtual time when the entity was disabled.
+    since: Duration,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayDisabled<T> {
+    /// Returns the elapsed virtual time when the entity was disabled.
+    pub fn since(&self) -> Duration {
+        self.since
+    }
+}
+
+/// System that skips the decay of the track `T` of an entity when it gains the disabling
+/// marker `D` while decaying on the track, recording when.
+pub(crate) fn skip_disabled<D: Component, T: Component>(
+    trigger: Trigger<OnAdd, D>,
+    mut commands: Commands,
+    time: Res<Time<Virtual>>,
+    query: Query<(), With<T>>,
+) {
+    if query.contains(trigger.entity()) {
+        disable::<T>(&mut commands, trigger.entity(), &time);
+    }
+}
+
+/// System that skips the decay of the track `T` of an entity starting to decay on the track
+/// while bearing the disabling marker `D`, recording when.
+pub(crate) fn skip_started_disabled<D: Component, T: Component>(
+    trigger: Trigger<OnAdd, T>,
+    mut commands: Commands,
+    time: Res<Time<Virtual>>,
+    query: Query<(), With<D>>,
+) {
+    if query.contains(trigger.entity()) {
+        disable::<T>(&mut commands, trigger.entity(), &time);
+    }
+}
+
+/// Marks the decay of the track `T` of the entity as disabled since now.
+fn disable<T: Component>(commands: &mut Commands, entity: Entity, time: &Time<Virtual>) {
+    commands.entity(entity).insert(DecayDisabled::<T> {
+        since: time.elapsed(),
+        track: PhantomData,
+    });
+}
+
+/// System that resumes the decay of the track `T` of an entity when it loses the disabling
+/// marker `D`, skipping the span spent disabled.
+pub(crate) fn resume_enabled<D: Component, T: Component>(
+    trigger: Trigger<OnRemove, D>,
+    mut commands: Commands,
+) {
+    commands
+        .entity(trigger.entity())
+        .remove::<DecayDisabled<T>>();
+}
+
+/// System that resumes the decay of the track `T` of an entity when it loses the disabling
+/// marker `D`, applying the span spent disabled.
+pub(crate) fn catch_up_enabled<D: Component, T: Component>(
+    trigger: Trigger<OnRemove, D>,
+    mut commands: Commands,
+    time: Res<Time<Virtual>>,
+    query: Query<&DecayDisabled<T>>,
+) {
+    let entity = trigger.entity();
+    let Ok(disabled) = query.get(entity) else {
+        return;
+    };
+
+    let span = time.elapsed().saturating_sub(disabled.since);
+    commands.entity(entity).remove::<DecayDisabled<T>>();
+    commands
+        .queue(move |world: &mut World| commands::fast_forward_entity::<T>(world, entity, span));
+}
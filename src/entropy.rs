@@ -0,0 +1,46 @@
+use crate::DecayDuration;
+use bevy::{ecs::system::SystemParam, prelude::*};
+#[cfg(feature = "bevy_rand")]
+use bevy_prng::WyRand;
+#[cfg(feature = "bevy_rand")]
+use bevy_rand::prelude::{EntropyComponent, GlobalEntropy};
+use std::{marker::PhantomData, time::Duration};
+
+/// System parameter providing the randomness rolling the durations of starting decays.
+///
+/// By default, durations are rolled with `thread_rng`. With the `bevy_rand` feature, they are
+/// sampled from the `EntropyComponent<WyRand>` of the entity if it has one, or from the
+/// `GlobalEntropy<WyRand>` resource otherwise, so projects standardizing on `bevy_rand` get
+/// consistent, forkable streams. Re-rolls of stacks and refreshes keep using `thread_rng`.
+#[derive(SystemParam)]
+pub struct DecayRng<'w, 's> {
+    /// The global entropy source, if inserted.
+    #[cfg(feature = "bevy_rand")]
+    global: Option<ResMut<'w, GlobalEntropy<WyRand>>>,
+    /// The entropy sources of the entities.
+    #[cfg(feature = "bevy_rand")]
+    entities: Query<'w, 's, &'static mut EntropyComponent<WyRand>>,
+    /// Binds the lifetimes of the parameter.
+    marker: PhantomData<(&'w (), &'s ())>,
+}
+
+impl DecayRng<'_, '_> {
+    /// Rolls a duration from the `DecayDuration` of the given entity.
+    #[cfg(not(feature = "bevy_rand"))]
+    pub fn roll(&mut self, _entity: Entity, duration: &DecayDuration) -> Duration {
+        Duration::from(duration)
+    }
+
+    /// Rolls a duration from the `DecayDuration` of the given entity.
+    #[cfg(feature = "bevy_rand")]
+    pub fn roll(&mut self, entity: Entity, duration: &DecayDuration) -> Duration {
+        if let Ok(mut entropy) = self.entities.get_mut(entity) {
+            return duration.roll(&mut *entropy);
+        }
+
+        match self.global.as_deref_mut() {
+            Some(global) => duration.roll(global),
+            None => Duration::from(duration),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{DecayTimer, Decaying};
+use bevy::{ecs::system::SystemParam, prelude::*};
+use std::{collections::BinaryHeap, time::Duration};
+
+/// System parameter querying the upcoming expirations of the decay track `T`.
+///
+/// Only entities actively decaying are considered; paused and frozen decays are ignored.
+#[derive(SystemParam)]
+pub struct DecayExpiries<'w, 's, T: Component> {
+    /// The timers of the entities actively decaying.
+    timers: Query<'w, 's, (Entity, &'static DecayTimer<T>), Decaying<T>>,
+}
+
+impl<T: Component> DecayExpiries<'_, '_, T> {
+    /// Returns the `n` entities with the smallest remaining time, along with that time,
+    /// sorted from the soonest to expire.
+    pub fn soonest(&self, n: usize) -> Vec<(Entity, Duration)> {
+        // Keep the `n` smallest remaining times in a max-heap, evicting the largest.
+        let capacity = n.saturating_add(1).min(self.timers.iter().len());
+        let mut heap = BinaryHeap::with_capacity(capacity);
+
+        for (entity, timer) in self.timers.iter() {
+            heap.push((timer.remaining(), entity));
+
+            if heap.len() > n {
+                heap.pop();
+            }
+        }
+
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|(remaining, entity)| (entity, remaining))
+            .collect()
+    }
+
+    /// Returns every entity whose decay completes within the given window (e.g. the next 30
+    /// seconds), along with its remaining time, sorted from the soonest to expire.
+    ///
+    /// Useful for admin tools, or for pre-warming the replacement spawns of expiring items.
+    pub fn within(&self, window: Duration) -> Vec<(Entity, Duration)> {
+        let mut expiring: Vec<_> = self
+            .timers
+            .iter()
+            .map(|(entity, timer)| (timer.remaining(), entity))
+            .filter(|(remaining, _)| *remaining <= window)
+            .collect();
+
+        expiring.sort();
+
+        expiring
+            .into_iter()
+            .map(|(remaining, entity)| (entity, remaining))
+            .collect()
+    }
+}
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Suspends the ticking of every decay track while no window of the app is focused, such as a
+/// minimized desktop game or a backgrounded mobile app.
+///
+/// Once a window is focused again, the span spent unfocused is skipped or applied all at once,
+/// according to the `catch_up` policy, instead of relying on how `Time` behaves meanwhile.
+/// Apps without any window, such as headless servers, are never suspended.
+///
+/// When this resource is present, it applies to every track at once, along with the rate
+/// effects, idle gates and decaying resources.
+#[derive(Resource, Debug)]
+pub struct DecayFocusSuspend {
+    /// How the span spent unfocused is handled once focused again.
+    pub catch_up: DecayCatchUp,
+    /// Whether the ticking is suspended.
+    suspended: bool,
+    /// The time elapsed while suspended.
+    missed: Duration,
+    /// The missed time applied during the current frame.
+    pending: Duration,
+}
+
+impl DecayFocusSuspend {
+    /// Creates a new `DecayFocusSuspend` handling the unfocused span as specified.
+    pub fn new(catch_up: DecayCatchUp) -> Self {
+        Self {
+            catch_up,
+            suspended: false,
+            missed: Duration::ZERO,
+            pending: Duration::ZERO,
+        }
+    }
+
+    /// Checks if the ticking is suspended.
+    pub fn suspended(&self) -> bool {
+        self.suspended
+    }
+
+    /// Returns the time the decays progress by during the current frame, given the frame time,
+    /// or `None` while suspended.
+    pub(crate) fn delta(&self, delta: Duration) -> Option<Duration> {
+        (!self.suspended).then(|| delta + self.pending)
+    }
+}
+
+/// How the span spent unfocused is handled by `DecayFocusSuspend` once focused again.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub enum DecayCatchUp {
+    /// The span is skipped, as if the app had been paused.
+    #[default]
+    Skip,
+    /// The span is applied all at once during the first focused frame.
+    ApplyAll,
+}
+
+/// Returns the time the decays progress by during the current frame.
+pub(crate) fn decay_delta(time: &Time, suspend: Option<&DecayFocusSuspend>) -> Option<Duration> {
+    match suspend {
+        Some(suspend) => suspend.delta(time.delta()),
+        None => Some(time.delta()),
+    }
+}
+
+/// System that suspends the decays while no window is focused, and catches up once focused.
+pub(crate) fn track_window_focus(
+    time: Res<Time>,
+    mut suspend: ResMut<DecayFocusSuspend>,
+    windows: Query<&Window>,
+) {
+    suspend.pending = Duration::ZERO;
+
+    // Headless apps have no window to lose focus, so they are always focused.
+    let focused = windows.is_empty() || windows.iter().any(|window| window.focused);
+    if !focused {
+        suspend.suspended = true;
+        suspend.missed += time.delta();
+    } else if suspend.suspended {
+        suspend.suspended = false;
+
+        let missed = std::mem::take(&mut suspend.missed);
+        if suspend.catch_up == DecayCatchUp::ApplyAll {
+            suspend.pending = missed;
+        }
+    }
+}
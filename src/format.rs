@@ -0,0 +1,106 @@
+use std::{fmt::Write, time::Duration};
+
+/// Unit of time used when formatting durations.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum TimeUnit {
+    /// Seconds, displayed as `s`.
+    Seconds,
+    /// Minutes, displayed as `m`.
+    Minutes,
+    /// Hours, displayed as `h`.
+    Hours,
+    /// Days, displayed as `d`.
+    Days,
+}
+
+impl TimeUnit {
+    /// All units, from the largest to the smallest.
+    const DESCENDING: [TimeUnit; 4] = [
+        TimeUnit::Days,
+        TimeUnit::Hours,
+        TimeUnit::Minutes,
+        TimeUnit::Seconds,
+    ];
+
+    /// The number of seconds in this unit.
+    fn seconds(self) -> u64 {
+        match self {
+            TimeUnit::Seconds => 1,
+            TimeUnit::Minutes => 60,
+            TimeUnit::Hours => 60 * 60,
+            TimeUnit::Days => 24 * 60 * 60,
+        }
+    }
+
+    /// The suffix of this unit in formatted durations.
+    fn suffix(self) -> &'static str {
+        match self {
+            TimeUnit::Seconds => "s",
+            TimeUnit::Minutes => "m",
+            TimeUnit::Hours => "h",
+            TimeUnit::Days => "d",
+        }
+    }
+}
+
+/// Formatting of durations into human-readable strings like `"2m 30s"` or `"3h"`.
+///
+/// The duration is rounded up to the `smallest_unit`, so a countdown never displays zero
+/// while time is still remaining. At most `max_units` units are displayed, starting from the
+/// largest non-zero one; units that are zero are omitted.
+#[derive(Clone, Copy, Debug)]
+pub struct DurationFormat {
+    /// The smallest unit displayed.
+    pub smallest_unit: TimeUnit,
+    /// The maximum number of units displayed.
+    pub max_units: usize,
+}
+
+impl Default for DurationFormat {
+    fn default() -> Self {
+        Self {
+            smallest_unit: TimeUnit::Seconds,
+            max_units: 2,
+        }
+    }
+}
+
+impl DurationFormat {
+    /// Formats the given duration.
+    pub fn format(&self, duration: Duration) -> String {
+        let unit_millis = u128::from(self.smallest_unit.seconds()) * 1000;
+        let mut remaining = duration.as_millis().div_ceil(unit_millis) as u64;
+        remaining *= self.smallest_unit.seconds();
+
+        let mut formatted = String::new();
+        let mut displayed = 0;
+
+        for unit in TimeUnit::DESCENDING {
+            if unit < self.smallest_unit || displayed >= self.max_units {
+                break;
+            }
+
+            let value = remaining / unit.seconds();
+            remaining %= unit.seconds();
+
+            // Skip the leading units that are zero, then count every unit towards the limit.
+            if value == 0 && displayed == 0 {
+                continue;
+            }
+            displayed += 1;
+
+            if value > 0 {
+                if !formatted.is_empty() {
+                    formatted.push(' ');
+                }
+                let _ = write!(formatted, "{value}{}", unit.suffix());
+            }
+        }
+
+        if formatted.is_empty() {
+            formatted = format!("0{}", self.smallest_unit.suffix());
+        }
+
+        formatted
+    }
+}
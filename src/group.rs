@@ -0,0 +1,195 @@
+use crate::{
+    is_finished, pause, Decay, DecayCharges, DecayCompleted, DecayFrozen, DecayTimer, NeverDecay,
+};
+use bevy::{
+    ecs::entity::{EntityHashMap, EntityHashSet},
+    prelude::*,
+};
+use std::marker::PhantomData;
+
+/// The pause source held on the members of a frozen group.
+pub(crate) const GROUP_PAUSE_SOURCE: &str = "decay group";
+
+/// Relation from a decaying entity to the group it belongs to, such as the root entity of a
+/// multi-part destructible.
+///
+/// Freezing the group entity, e.g. with
+/// [`pause_decay`](crate::DecayEntityCommandsExt::pause_decay), pauses the decay of its members
+/// with the `"decay group"` source of their `DecayPauseSources`, reported as
+/// [`DecayPauseReason::GroupPaused`](crate::DecayPauseReason::GroupPaused), until the group
+/// entity is thawed.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DecayGroup(pub Entity);
+
+/// Marker component for group entities whose members complete their decay together.
+///
+/// Members whose timer has finished wait for the rest of the group, and the whole group
+/// completes at the moment the last member's timer finishes, in a single `DecayCompleted`
+/// event followed by a `DecayGroupCompleted` event. A `DecayCompletionBudget` may still spread
+/// a large group over several frames.
+#[derive(Component, Default, Debug)]
+pub struct DecayBarrier;
+
+/// Event triggered on a group entity with a `DecayBarrier` once its members have completed
+/// their decay of the track `T` together.
+#[derive(Event, Debug)]
+pub struct DecayGroupCompleted<T: Component = Decay> {
+    /// The members of the group that have completed decaying.
+    pub members: Vec<Entity>,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayGroupCompleted<T> {
+    /// Creates a new `DecayGroupCompleted` event for the given members.
+    pub fn new(members: Vec<Entity>) -> Self {
+        Self {
+            members,
+            track: PhantomData,
+        }
+    }
+}
+
+/// Members of group barriers waiting for the rest of their group on the track `T`.
+///
+/// Updated every frame by [`hold_group_barriers`], before the completions.
+#[derive(Resource)]
+pub struct DecayBarriers<T: Component = Decay> {
+    /// The members whose group has not finished yet.
+    pub(crate) held: EntityHashSet,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> Default for DecayBarriers<T> {
+    fn default() -> Self {
+        Self {
+            held: EntityHashSet::default(),
+            track: PhantomData,
+        }
+    }
+}
+
+/// System that holds the members of group barriers until every member of the group has
+/// finished decaying on the track `T`.
+pub fn hold_group_barriers<T: Component>(
+    mut barriers: ResMut<DecayBarriers<T>>,
+    groups: Query<(), With<DecayBarrier>>,
+    members: Query<
+        (Entity, &DecayGroup, &DecayTimer<T>, Option<&DecayCharges>),
+        (With<T>, Without<NeverDecay>),
+    >,
+) {
+    // Find the barrier groups with a member still decaying.
+    let pending: EntityHashSet = members
+        .iter()
+        .filter(|(_, group, timer, charges)| {
+            groups.contains(group.0) && !is_finished(timer, *charges)
+        })
+        .map(|(_, group, ..)| group.0)
+        .collect();
+
+    barriers.held.clear();
+    barriers.held.extend(
+        members
+            .iter()
+            .filter(|(_, group, ..)| pending.contains(&group.0))
+            .map(|(entity, ..)| entity),
+    );
+}
+
+/// System that triggers `DecayGroupCompleted` on the barrier groups whose members have
+/// completed decaying.
+pub(crate) fn trigger_group_completed<T: Component>(
+    trigger: Trigger<DecayCompleted<T>>,
+    mut commands: Commands,
+    groups: Query<(), With<DecayBarrier>>,
+    members: Query<&DecayGroup>,
+) {
+    let mut completed: EntityHashMap<Vec<Entity>> = EntityHashMap::default();
+    for &entity in trigger.iter() {
+        if let Some(group) = members
+            .get(entity)
+            .ok()
+            .filter(|group| groups.contains(group.0))
+        {
+            completed.entry(group.0).or_default().push(entity);
+        }
+    }
+
+    for (group, members) in completed {
+        commands.trigger_targets(DecayGroupCompleted::<T>::new(members), group);
+    }
+}
+
+/// System that pauses the decay of the members of a group when the group entity is frozen.
+pub(crate) fn pause_group_members(
+    trigger: Trigger<OnAdd, DecayFrozen>,
+    mut commands: Commands,
+    members: Query<(Entity, &DecayGroup)>,
+) {
+    let group = trigger.entity();
+    for (member, _) in members.iter().filter(|(_, member)| member.0 == group) {
+        commands.queue(move |world: &mut World| update_member(world, member, true));
+    }
+}
+
+/// System that resumes the decay of the members of a group when the group entity is thawed,
+/// including when it is despawned.
+pub(crate) fn resume_group_members(
+    trigger: Trigger<OnRemove, DecayFrozen>,
+    mut commands: Commands,
+    members: Query<(Entity, &DecayGroup)>,
+) {
+    let group = trigger.entity();
+    for (member, _) in members.iter().filter(|(_, member)| member.0 == group) {
+        commands.queue(move |world: &mut World| update_member(world, member, false));
+    }
+}
+
+/// System that pauses the decay of entities joining a frozen group.
+pub(crate) fn handle_group_joined(
+    trigger: Trigger<OnInsert, DecayGroup>,
+    mut commands: Commands,
+    members: Query<&DecayGroup>,
+    frozen: Query<(), With<DecayFrozen>>,
+) {
+    let member = trigger.entity();
+    if members
+        .get(member)
+        .is_ok_and(|group| frozen.contains(group.0))
+    {
+        commands.queue(move |world: &mut World| update_member(world, member, true));
+    }
+}
+
+/// System that resumes the decay of entities leaving a frozen group, including when they are
+/// despawned.
+pub(crate) fn handle_group_left(
+    trigger: Trigger<OnReplace, DecayGroup>,
+    mut commands: Commands,
+    members: Query<&DecayGroup>,
+    frozen: Query<(), With<DecayFrozen>>,
+) {
+    let member = trigger.entity();
+    if members
+        .get(member)
+        .is_ok_and(|group| frozen.contains(group.0))
+    {
+        commands.queue(move |world: &mut World| update_member(world, member, false));
+    }
+}
+
+/// Adds or releases the pause of the group source on the member, unless it was despawned
+/// meanwhile.
+fn update_member(world: &mut World, member: Entity, paused: bool) {
+    let Ok(mut member) = world.get_entity_mut(member) else {
+        return;
+    };
+
+    if paused {
+        pause::pause(&mut member, GROUP_PAUSE_SOURCE.into());
+    } else {
+        pause::resume(&mut member, GROUP_PAUSE_SOURCE);
+    }
+}
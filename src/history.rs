@@ -0,0 +1,123 @@
+use crate::{Decay, DecayCompleted, DecayPaused, DecayStarted, DecayStartedBatch};
+use bevy::prelude::*;
+use std::{collections::VecDeque, marker::PhantomData, time::Duration};
+
+/// Ring buffer of the most recent decay events of the track `T` (`Decay` by default).
+///
+/// Retains the last records of started, paused and completed decays with the time they
+/// happened at, so crash dumps and admin tools can answer "what decayed in the last minute"
+/// without an external logging pipeline. Enable it with
+/// [`DecayPlugin::with_history`](crate::DecayPlugin::with_history).
+#[derive(Resource, Debug)]
+pub struct DecayHistory<T: Component = Decay> {
+    /// The maximum number of records retained.
+    capacity: usize,
+    /// The records, from the oldest to the most recent.
+    records: VecDeque<DecayHistoryRecord>,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayHistory<T> {
+    /// Creates a new `DecayHistory` retaining the given number of records.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            records: VecDeque::with_capacity(capacity),
+            track: PhantomData,
+        }
+    }
+
+    /// Returns the maximum number of records retained.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Iterates over the records, from the oldest to the most recent.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &DecayHistoryRecord> {
+        self.records.iter()
+    }
+
+    /// Iterates over the records that happened at or after the given time.
+    pub fn since(&self, time: Duration) -> impl Iterator<Item = &DecayHistoryRecord> {
+        self.records
+            .iter()
+            .filter(move |record| record.time >= time)
+    }
+
+    /// Records an event, dropping the oldest record when full.
+    fn record(&mut self, entity: Entity, kind: DecayHistoryKind, time: Duration) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.records.len() == self.capacity {
+            self.records.pop_front();
+        }
+
+        self.records
+            .push_back(DecayHistoryRecord { entity, kind, time });
+    }
+}
+
+/// A decay event retained by the `DecayHistory`.
+#[derive(Clone, Copy, Debug)]
+pub struct DecayHistoryRecord {
+    /// The entity the event happened to.
+    pub entity: Entity,
+    /// The kind of event.
+    pub kind: DecayHistoryKind,
+    /// The elapsed time of the app when the event happened.
+    pub time: Duration,
+}
+
+/// Kind of event retained by the `DecayHistory`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum DecayHistoryKind {
+    /// The decay started or resumed.
+    Started,
+    /// The decay was paused.
+    Paused,
+    /// The decay completed.
+    Completed,
+}
+
+/// System that records the started decays of the track `T`.
+pub(crate) fn record_started<T: Component>(
+    trigger: Trigger<DecayStarted<T>>,
+    time: Res<Time>,
+    mut history: ResMut<DecayHistory<T>>,
+) {
+    history.record(trigger.entity, DecayHistoryKind::Started, time.elapsed());
+}
+
+/// System that records the batched started decays of the track `T`.
+pub(crate) fn record_started_batch<T: Component>(
+    trigger: Trigger<DecayStartedBatch<T>>,
+    time: Res<Time>,
+    mut history: ResMut<DecayHistory<T>>,
+) {
+    for &(entity, _) in trigger.iter() {
+        history.record(entity, DecayHistoryKind::Started, time.elapsed());
+    }
+}
+
+/// System that records the paused decays of the track `T`.
+pub(crate) fn record_paused<T: Component>(
+    trigger: Trigger<DecayPaused<T>>,
+    time: Res<Time>,
+    mut history: ResMut<DecayHistory<T>>,
+) {
+    history.record(trigger.entity, DecayHistoryKind::Paused, time.elapsed());
+}
+
+/// System that records the completed decays of the track `T`.
+pub(crate) fn record_completed<T: Component>(
+    trigger: Trigger<DecayCompleted<T>>,
+    time: Res<Time>,
+    mut history: ResMut<DecayHistory<T>>,
+) {
+    for &entity in trigger.iter() {
+        history.record(entity, DecayHistoryKind::Completed, time.elapsed());
+    }
+}
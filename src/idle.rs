@@ -0,0 +1,53 @@
+use crate::{focus, DecayFocusSuspend};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Gates the decay of an entity on it being stationary.
+///
+/// The entity only decays once its `GlobalTransform` has not changed for the `grace` period, so
+/// dropped items rot while carried or thrown ones do not. Any movement, including the one of a
+/// parent carrying the entity, pauses the decay again until the entity has been stationary for
+/// the whole grace period.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Transform)]
+pub struct DecayWhenIdle {
+    /// The duration the entity must be stationary before decaying.
+    pub grace: Duration,
+    /// The duration the entity has been stationary for.
+    idle: Duration,
+}
+
+impl DecayWhenIdle {
+    /// Creates a new `DecayWhenIdle` with the given grace period.
+    pub fn new(grace: Duration) -> Self {
+        Self {
+            grace,
+            idle: Duration::ZERO,
+        }
+    }
+
+    /// Checks if the entity has been stationary for the whole grace period.
+    pub fn is_idle(&self) -> bool {
+        self.idle >= self.grace
+    }
+}
+
+/// System that tracks how long entities gated by `DecayWhenIdle` have been stationary.
+pub(crate) fn track_idle(
+    time: Res<Time>,
+    suspend: Option<Res<DecayFocusSuspend>>,
+    mut query: Query<(&mut DecayWhenIdle, Ref<GlobalTransform>)>,
+) {
+    // While the app is unfocused, the grace period is suspended along with the decays.
+    let Some(delta) = focus::decay_delta(&time, suspend.as_deref()) else {
+        return;
+    };
+
+    for (mut when_idle, transform) in query.iter_mut() {
+        if transform.is_changed() {
+            when_idle.idle = Duration::ZERO;
+        } else if !when_idle.is_idle() {
+            when_idle.idle += delta;
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{Decay, DecayTimer};
+use bevy::prelude::*;
+use std::{f32::consts::TAU, marker::PhantomData};
+
+/// Plugin drawing progress indicators above the entities decaying on the track `T` (`Decay` by
+/// default) that bear a `DecayIndicator`.
+///
+/// The indicators are drawn with gizmos, facing the first camera found, so playtesters can see
+/// at a glance which objects are about to expire. Use `DecayIndicatorPlugin::default()` for the
+/// `Decay` track and `DecayIndicatorPlugin::<T>::new()` for any other track.
+pub struct DecayIndicatorPlugin<T: Component = Decay> {
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+// `Default` is only implemented for the `Decay` track, so `DecayIndicatorPlugin::default()`
+// can be inferred without naming the track.
+#[allow(clippy::new_without_default)]
+impl<T: Component> DecayIndicatorPlugin<T> {
+    /// Creates a new `DecayIndicatorPlugin` for the decay track of the marker component `T`.
+    pub fn new() -> Self {
+        Self { track: PhantomData }
+    }
+}
+
+impl Default for DecayIndicatorPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Component> Plugin for DecayIndicatorPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, draw_decay_indicators::<T>);
+    }
+}
+
+/// Marker component drawing a progress indicator above a decaying entity.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Transform)]
+pub struct DecayIndicator {
+    /// The offset of the indicator from the entity.
+    pub offset: Vec3,
+    /// The width of the bar, or the diameter of the ring.
+    pub size: f32,
+    /// The shape of the indicator.
+    pub style: DecayIndicatorStyle,
+}
+
+impl Default for DecayIndicator {
+    fn default() -> Self {
+        Self {
+            offset: Vec3::Y,
+            size: 1.0,
+            style: DecayIndicatorStyle::Bar,
+        }
+    }
+}
+
+/// Shape of a `DecayIndicator`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub enum DecayIndicatorStyle {
+    /// A bar shrinking as the decay progresses.
+    #[default]
+    Bar,
+    /// A ring closing as the decay progresses.
+    Radial,
+}
+
+/// The number of segments of a full radial indicator.
+const RADIAL_SEGMENTS: usize = 32;
+
+/// System that draws the progress indicators of entities decaying on the track `T`.
+fn draw_decay_indicators<T: Component>(
+    mut gizmos: Gizmos,
+    cameras: Query<&GlobalTransform, With<Camera>>,
+    query: Query<(&DecayIndicator, &DecayTimer<T>, &GlobalTransform), With<T>>,
+) {
+    // Face the camera, or the default orientation without one.
+    let (right, up) = cameras.iter().next().map_or((Vec3::X, Vec3::Y), |camera| {
+        (camera.right().as_vec3(), camera.up().as_vec3())
+    });
+
+    for (indicator, timer, transform) in query.iter() {
+        let remaining = 1.0 - timer.fraction();
+        let center = transform.translation() + indicator.offset;
+        let color = Color::srgb(1.0 - remaining, remaining, 0.0);
+        let half = indicator.size / 2.0;
+
+        match indicator.style {
+            DecayIndicatorStyle::Bar => {
+                let start = center - right * half;
+                gizmos.line(start, center + right * half, Color::BLACK);
+                gizmos.line(start, start + right * indicator.size * remaining, color);
+            }
+            DecayIndicatorStyle::Radial => {
+                let segments = (RADIAL_SEGMENTS as f32 * remaining).ceil() as usize;
+                gizmos.linestrip(
+                    (0..=segments).map(|segment| {
+                        let angle = TAU * remaining * segment as f32 / segments.max(1) as f32;
+                        center + (up * angle.cos() + right * angle.sin()) * half
+                    }),
+                    color,
+                );
+            }
+        }
+    }
+}
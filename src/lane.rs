@@ -0,0 +1,253 @@
+use crate::{
+    focus, Decay, DecayDisabled, DecayFocusSuspend, DecayFrozen, DecaySpeed, DecaySpeedItem,
+    DecayTimer, NeverDecay,
+};
+use bevy::prelude::*;
+use std::{marker::PhantomData, time::Duration};
+
+/// Resource moving the very long decays of the track `T` (days to weeks) out of the per-frame
+/// ticking into a coarse lane checked every `interval`.
+///
+/// Every interval, running decays whose remaining time is above the `threshold` join the lane
+/// and stop being visited by [`decaying`](crate::decaying), and the members of the lane are
+/// ticked with the time elapsed since their last check. A member returns to the precise lane
+/// once its remaining time falls to the threshold, or would run out within two intervals at its
+/// current speed, so it still completes on time.
+///
+/// Between checks, the timers of the members lag behind by up to one interval. Pausing,
+/// freezing or completing a member brings its timer up to date before it leaves the lane.
+///
+/// It is managed by the plugin; see [`DecayPlugin::with_long_lane`].
+///
+/// [`DecayPlugin::with_long_lane`]: crate::DecayPlugin::with_long_lane
+#[derive(Resource, Debug)]
+pub struct DecayLongLane<T: Component = Decay> {
+    /// The remaining time above which decays join the lane.
+    threshold: Duration,
+    /// The timer counting down the interval between checks.
+    interval: Timer,
+    /// The decay time elapsed since the lane was created.
+    clock: Duration,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayLongLane<T> {
+    /// Creates a new `DecayLongLane` for decays longer than `threshold`, checked every
+    /// `interval`.
+    pub(crate) fn new(threshold: Duration, interval: Duration) -> Self {
+        Self {
+            threshold,
+            interval: Timer::new(interval, TimerMode::Repeating),
+            clock: Duration::ZERO,
+            track: PhantomData,
+        }
+    }
+
+    /// Returns the remaining time above which decays join the lane.
+    pub fn threshold(&self) -> Duration {
+        self.threshold
+    }
+
+    /// Returns the interval between checks of the lane.
+    pub fn interval(&self) -> Duration {
+        self.interval.duration()
+    }
+
+    /// Checks if a decay with the given remaining time and speed multiplier belongs to the lane.
+    fn is_long(&self, remaining: Duration, multiplier: f32) -> bool {
+        // At its current speed, the decay must not run out before the next checks.
+        let real_remaining = if multiplier > 0.0 {
+            remaining.div_f32(multiplier)
+        } else {
+            Duration::MAX
+        };
+
+        remaining > self.threshold && real_remaining > self.interval() * 2
+    }
+}
+
+/// Marker component for entities whose decay of the track `T` is ticked by the
+/// `DecayLongLane` instead of every frame.
+///
+/// It is managed by the plugin, and only public so the members can be queried or excluded.
+#[derive(Component, Debug)]
+pub struct DecayLongLaneMember<T: Component = Decay> {
+    /// The clock of the lane at the last check of the entity.
+    since: Duration,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+/// Query filter for the members of the long lane of the track `T` that are ticked.
+type MemberFilter<T> = (
+    With<T>,
+    Without<DecayFrozen>,
+    Without<NeverDecay>,
+    Without<DecayDisabled<T>>,
+);
+
+/// Holds the decay time elapsed since the last check of the member back in its timer, to be
+/// applied by the next tick.
+fn settle<T: Component>(
+    timer: &mut DecayTimer<T>,
+    member: &mut DecayLongLaneMember<T>,
+    speed: &DecaySpeedItem,
+    clock: Duration,
+) {
+    let elapsed = clock.saturating_sub(member.since);
+    timer.pending += elapsed.mul_f32(speed.multiplier_at(timer.fraction()));
+    member.since = clock;
+}
+
+/// System that ticks the members of the long lane of the track `T` every interval, and moves
+/// the decays between the lanes.
+pub(crate) fn check_long_lane<T: Component>(
+    mut commands: Commands,
+    time: Res<Time>,
+    suspend: Option<Res<DecayFocusSuspend>>,
+    mut lane: ResMut<DecayLongLane<T>>,
+    mut members: Query<
+        (
+            Entity,
+            &mut DecayTimer<T>,
+            &mut DecayLongLaneMember<T>,
+            DecaySpeed,
+        ),
+        MemberFilter<T>,
+    >,
+    mut candidates: Query<
+        (Entity, &mut DecayTimer<T>, DecaySpeed),
+        (
+            With<T>,
+            Without<DecayLongLaneMember<T>>,
+            Without<DecayFrozen>,
+            Without<NeverDecay>,
+            Without<DecayDisabled<T>>,
+        ),
+    >,
+) {
+    // While the app is unfocused, the decays may be suspended.
+    let delta = focus::decay_delta(&time, suspend.as_deref()).unwrap_or_default();
+    lane.clock += delta;
+
+    lane.interval.tick(time.delta());
+    if !lane.interval.finished() {
+        return;
+    }
+
+    let clock = lane.clock;
+
+    // Tick the members, returning the ones nearing completion to the precise lane.
+    for (entity, mut timer, mut member, speed) in members.iter_mut() {
+        settle(&mut timer, &mut member, &speed, clock);
+
+        if !timer.paused() {
+            let pending = std::mem::take(&mut timer.pending);
+            timer.tick(pending);
+        }
+
+        if !lane.is_long(timer.remaining(), speed.multiplier_at(timer.fraction())) {
+            commands.entity(entity).remove::<DecayLongLaneMember<T>>();
+        }
+    }
+
+    // Move the long running decays into the lane, applying the time held back first.
+    for (entity, mut timer, speed) in candidates.iter_mut() {
+        if timer.paused() || !lane.is_long(timer.remaining(), speed.multiplier_at(timer.fraction()))
+        {
+            continue;
+        }
+
+        let pending = std::mem::take(&mut timer.pending);
+        timer.tick(pending);
+
+        commands.entity(entity).insert(DecayLongLaneMember::<T> {
+            since: clock,
+            track: PhantomData,
+        });
+    }
+}
+
+/// Returns the time until the long lane of the track `T` must be checked for its first member
+/// to return to the precise lane, if it has any members that progress.
+///
+/// Used by simulations, so their steps do not skip past the departure of a member.
+pub(crate) fn next_check<T: Component>(world: &mut World) -> Option<Duration> {
+    let mut members = world
+        .query_filtered::<(&DecayTimer<T>, &DecayLongLaneMember<T>, DecaySpeed), MemberFilter<T>>();
+    let lane = world.get_resource::<DecayLongLane<T>>()?;
+
+    let departure = members
+        .iter(world)
+        .filter(|(timer, ..)| !timer.paused())
+        .filter_map(|(timer, member, speed)| {
+            // Members that do not progress never leave the lane.
+            let multiplier = speed.multiplier_at(timer.fraction());
+            if multiplier <= 0.0 {
+                return None;
+            }
+
+            // Account for the time elapsed since the last check of the member.
+            let elapsed = lane.clock.saturating_sub(member.since).mul_f32(multiplier);
+            let remaining = timer.remaining().saturating_sub(elapsed);
+
+            // The member leaves once its remaining time falls to the threshold, or it would run
+            // out within two intervals.
+            let to_threshold = remaining.saturating_sub(lane.threshold).div_f32(multiplier);
+            let to_interval = remaining
+                .div_f32(multiplier)
+                .saturating_sub(lane.interval() * 2);
+            Some(to_threshold.min(to_interval))
+        })
+        .min()?;
+
+    // Members only leave the lane when it is checked.
+    Some(departure.max(lane.interval.remaining()))
+}
+
+/// System that returns members of the long lane to the precise lane when their decay of the
+/// track `T` is paused or completed.
+pub(crate) fn leave_long_lane_on_pause<T: Component>(
+    trigger: Trigger<OnRemove, T>,
+    commands: Commands,
+    lane: Res<DecayLongLane<T>>,
+    query: Query<(&mut DecayTimer<T>, &mut DecayLongLaneMember<T>, DecaySpeed)>,
+) {
+    leave(trigger.entity(), commands, &lane, query);
+}
+
+/// System that returns members of the long lane to the precise lane when they are frozen.
+pub(crate) fn leave_long_lane_on_freeze<T: Component>(
+    trigger: Trigger<OnAdd, DecayFrozen>,
+    commands: Commands,
+    lane: Res<DecayLongLane<T>>,
+    query: Query<(&mut DecayTimer<T>, &mut DecayLongLaneMember<T>, DecaySpeed)>,
+) {
+    leave(trigger.entity(), commands, &lane, query);
+}
+
+/// System that returns members of the long lane to the precise lane when their decay of the
+/// track `T` is skipped while disabled.
+pub(crate) fn leave_long_lane_on_disable<T: Component>(
+    trigger: Trigger<OnAdd, DecayDisabled<T>>,
+    commands: Commands,
+    lane: Res<DecayLongLane<T>>,
+    query: Query<(&mut DecayTimer<T>, &mut DecayLongLaneMember<T>, DecaySpeed)>,
+) {
+    leave(trigger.entity(), commands, &lane, query);
+}
+
+/// Returns the entity to the precise lane, holding the time elapsed since its last check in
+/// its timer.
+fn leave<T: Component>(
+    entity: Entity,
+    mut commands: Commands,
+    lane: &DecayLongLane<T>,
+    mut query: Query<(&mut DecayTimer<T>, &mut DecayLongLaneMember<T>, DecaySpeed)>,
+) {
+    if let Ok((mut timer, mut member, speed)) = query.get_mut(entity) {
+        settle(&mut timer, &mut member, &speed, lane.clock);
+        commands.entity(entity).remove::<DecayLongLaneMember<T>>();
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{Decay, DecayTimer};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Interpolation of a field of the component `C` over the progress of a decay.
+///
+/// Implemented for any `Fn(&mut C, f32)` closure, so simple fields need no dedicated type;
+/// non-capturing closures coerce to `fn(&mut C, f32)`, the default lens of `DecayField`.
+pub trait DecayLens<C: Component>: Send + Sync + 'static {
+    /// Sets the field of the target to its value once the given fraction of the decay has
+    /// elapsed, from 0 to 1.
+    fn lerp(&self, target: &mut C, ratio: f32);
+}
+
+impl<C: Component, F: Fn(&mut C, f32) + Send + Sync + 'static> DecayLens<C> for F {
+    fn lerp(&self, target: &mut C, ratio: f32) {
+        self(target, ratio)
+    }
+}
+
+/// Interpolates a field of the component `C` of an entity across the duration of its decay of
+/// the track `T` (`Decay` by default), e.g. the alpha of a sprite or the volume of a sound.
+///
+/// The field is updated every frame the decay progresses, through the lens `L`. It is handled
+/// by the `DecayPlugin` of every track registered with [`DecayPlugin::with_field`], such as
+/// `with_field::<Sprite, fn(&mut Sprite, f32)>()` for the default lens.
+///
+/// [`DecayPlugin::with_field`]: crate::DecayPlugin::with_field
+#[derive(Component)]
+pub struct DecayField<C: Component, L: DecayLens<C> = fn(&mut C, f32), T: Component = Decay> {
+    /// The lens interpolating the field.
+    pub lens: L,
+    /// The interpolated component and the marker component of the decay track.
+    track: PhantomData<(C, T)>,
+}
+
+impl<C: Component, L: DecayLens<C>, T: Component> DecayField<C, L, T> {
+    /// Creates a new `DecayField` interpolating the field through the given lens.
+    pub fn new(lens: L) -> Self {
+        Self {
+            lens,
+            track: PhantomData,
+        }
+    }
+}
+
+/// System that interpolates the fields of entities decaying on the track `T`.
+pub(crate) fn apply_decay_fields<C: Component, L: DecayLens<C>, T: Component>(
+    mut query: Query<(&DecayField<C, L, T>, Ref<DecayTimer<T>>, &mut C), With<T>>,
+) {
+    for (field, timer, mut target) in query.iter_mut() {
+        // Paused decays keep their fields as they are.
+        if timer.is_changed() {
+            field.lens.lerp(&mut target, timer.fraction());
+        }
+    }
+}
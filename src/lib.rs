@@ -1,20 +1,276 @@
-use bevy::prelude::*;
+mod action;
+mod after;
+mod app;
+mod budget;
+mod cascade;
+mod chain;
+mod cleanup;
+mod commands;
+#[cfg(feature = "console")]
+mod console;
+#[cfg(feature = "leafwing_abilities")]
+mod cooldown;
+mod debug;
+#[cfg(feature = "asset")]
+mod definition;
+mod despawn;
+mod diagnostic;
+mod disabled;
+mod entropy;
+mod expiry;
+mod focus;
+mod format;
+mod group;
+mod history;
+mod idle;
+mod indicator;
+mod lane;
+mod lens;
+mod lod;
+mod on_component;
+mod owner;
+mod path;
+mod pause;
+#[cfg(feature = "persistence")]
+mod persistence;
+mod predict;
+pub mod presets;
+#[cfg(feature = "rapier")]
+mod rapier;
+mod rate;
+mod refresh;
+mod region;
+mod report;
+mod resource;
+mod restore;
+mod scan;
+mod simulate;
+#[cfg(feature = "serde")]
+mod snapshot;
+mod sprite;
+#[cfg(feature = "seldom_state")]
+mod state_machine;
+mod step;
+mod streaming;
+#[cfg(feature = "metrics")]
+mod telemetry;
+#[cfg(feature = "text")]
+mod text;
+#[cfg(feature = "tilemap")]
+mod tilemap;
+#[cfg(feature = "time_runner")]
+mod time_runner;
+mod wall_clock;
+
+pub use action::{DecayContents, DecayLoot, OnDecayCommand, OnDecaySpawn, OnDecayTrigger};
+pub use after::DecayAfter;
+pub use app::DecayAppExt;
+pub use budget::{DecayCompletionBudget, DecayConcurrency, DecayPriority, DecayQueueOrder};
+pub use cascade::InheritDecayState;
+pub use chain::DecayThen;
+pub use cleanup::DecayTimerOrphaned;
+pub use commands::{DecayCommandsExt, DecayEntityCommandsExt};
+#[cfg(feature = "console")]
+pub use console::DecayConsolePlugin;
+#[cfg(feature = "leafwing_abilities")]
+pub use cooldown::DecayCooldownCommandsExt;
+pub use debug::DecayDebugPlugin;
+#[cfg(feature = "asset")]
+pub use definition::{
+    DecayDefinition, DecayDefinitionHandle, DecayDefinitionLoaderError, DecayDefinitionPlugin,
+    DecayReloadPolicy,
+};
+pub use despawn::{DecayDespawner, DecayDespawning};
+pub use diagnostic::{
+    DecayArchetypeMetrics, DecayExpirationHistogram, DecayOvershootHistogram, DecayTrackMetrics,
+};
+pub use disabled::{DecayDisabled, DecayWhileDisabled};
+pub use entropy::DecayRng;
+pub use expiry::DecayExpiries;
+pub use focus::{DecayCatchUp, DecayFocusSuspend};
+pub use format::{DurationFormat, TimeUnit};
+pub use group::{
+    hold_group_barriers, DecayBarrier, DecayBarriers, DecayGroup, DecayGroupCompleted,
+};
+pub use history::{DecayHistory, DecayHistoryKind, DecayHistoryRecord};
+pub use idle::DecayWhenIdle;
+pub use indicator::{DecayIndicator, DecayIndicatorPlugin, DecayIndicatorStyle};
+pub use lane::{DecayLongLane, DecayLongLaneMember};
+pub use lens::{DecayField, DecayLens};
+pub use lod::{DecayLod, DecayLodAnchor, DecayLodPolicy, DecayLodSchedule, DecayLodWaiting};
+pub use on_component::{CancelDecayOn, StartDecayOn};
+pub use owner::{DecayOwner, DecayOwnerPlugin, Offline};
+pub use path::{set_decay_path, DecayControl, DecayPathError};
+pub use pause::{DecayPauseGuard, DecayPauseSources};
+#[cfg(feature = "persistence")]
+pub use persistence::{
+    DecayPersistencePlugin, DecaySnapshotRequested, DecayStore, DecayStoreBatch,
+};
+pub use predict::{DecayServerClock, PredictedDecay, PredictedDecayPlugin};
+#[cfg(feature = "rapier")]
+pub use rapier::{DecayRestMode, DecayWhenAtRest};
+pub use rate::{DecayModifiers, DecayRateEffect, DecaySpeed, DecaySpeedItem, DecaySpeedSchedule};
+pub use refresh::{DecayRefresh, RefreshDecayOnChange, RerollDecayOnResume};
+pub use region::DecayRegion;
+pub use report::DecayReportPlugin;
+pub use resource::{
+    DecayResourcePlugin, DecayingResource, ResourceDecayCompleted, ResourceDecayPaused,
+    ResourceDecayStarted,
+};
+pub use restore::{apply_decay_state, extract_decay_state, DecayRecord, DecayState, RestoreDecays};
+pub use scan::DecayScan;
+pub use simulate::simulate;
+#[cfg(feature = "ron")]
+pub use snapshot::{
+    export_decay_snapshot, import_decay_snapshot, DecaySnapshotExported, DecaySnapshotMigrations,
+    DecaySnapshotRonError,
+};
+#[cfg(feature = "serde")]
+pub use snapshot::{DecaySnapshot, DecaySnapshotEntry, DecaySnapshotError, DECAY_SNAPSHOT_VERSION};
+pub use sprite::{DecaySprite, DecayStageSprites};
+#[cfg(feature = "seldom_state")]
+pub use state_machine::{decay_fraction_at_least, DecayStateMachinePlugin};
+pub use step::{step, step_track};
+pub use streaming::{DecayStreamingPlugin, DecayUnloadedAt, Unloaded};
+#[cfg(feature = "text")]
+pub use text::DecayCountdownText;
+#[cfg(feature = "tilemap")]
+pub use tilemap::DecayTileAction;
+#[cfg(feature = "time_runner")]
+pub use time_runner::DecayTimeRunner;
+pub use wall_clock::{DecayAt, DecayWallClock};
+
+use bevy::{
+    ecs::{
+        entity::{Entities, EntityHashSet},
+        query::QueryData,
+    },
+    prelude::*,
+    utils::{Instant, Parallel},
+};
 use rand::Rng;
-use std::time::Duration;
+use std::{
+    cmp::Reverse,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    time::Duration,
+};
 
 pub mod prelude {
     pub use super::{
-        Decay, DecayCompleted, DecayDuration, DecayPaused, DecayPlugin, DecayStarted, DecayingSet,
+        CancelDecayOn, Decay, DecayAction, DecayAfter, DecayAppExt, DecayArchetypeMetrics, DecayAt,
+        DecayBarrier, DecayBundle, DecayCatchUp, DecayCharges, DecayCommandsExt, DecayCompleted,
+        DecayCompletedOf, DecayCompletionBudget, DecayConcurrency, DecayContents, DecayControl,
+        DecayDebugPlugin, DecayDelay, DecayDespawner, DecayDespawning, DecayDisabled,
+        DecayDuration, DecayEntityCommandsExt, DecayExpirationHistogram, DecayExpiries, DecayField,
+        DecayFocusSuspend, DecayFrozen, DecayGroup, DecayGroupCompleted, DecayHistory,
+        DecayIndicator, DecayIndicatorPlugin, DecayIndicatorStyle, DecayLens, DecayLod,
+        DecayLodAnchor, DecayLodPolicy, DecayLodWaiting, DecayLongLane, DecayLongLaneMember,
+        DecayLoot, DecayModifiers, DecayOvershootHistogram, DecayOwner, DecayOwnerPlugin,
+        DecayPauseGuard, DecayPauseReason, DecayPauseSources, DecayPaused, DecayPlugin,
+        DecayPriority, DecayQueueOrder, DecayRateEffect, DecayRecord, DecayRefresh, DecayRegion,
+        DecayReportPlugin, DecayResourcePlugin, DecayServerClock, DecaySpeedSchedule, DecaySprite,
+        DecayStackConsumed, DecayStacks, DecayStageSprites, DecayStartOffset, DecayStarted,
+        DecayStartedBatch, DecayState, DecayStreamingPlugin, DecayTag, DecayThen, DecayTimer,
+        DecayTimerOrphaned, DecayTrackMetrics, DecayTrackSet, DecayUnloadedAt, DecayWallClock,
+        DecayWhenIdle, DecayWhileDisabled, Decaying, DecayingResource, DecayingSet, DurationFormat,
+        InheritDecayState, NeverDecay, Offline, OnDecayCommand, OnDecaySpawn, OnDecayTrigger,
+        PredictedDecay, PredictedDecayPlugin, RecentlyStartedDecay, RefreshDecayOnChange,
+        RerollDecayOnResume, ResourceDecayCompleted, ResourceDecayPaused, ResourceDecayStarted,
+        RestoreDecays, StartDecayOn, TimeUnit, Unloaded,
+    };
+
+    #[cfg(feature = "asset")]
+    pub use super::{
+        DecayDefinition, DecayDefinitionHandle, DecayDefinitionPlugin, DecayReloadPolicy,
     };
+
+    #[cfg(feature = "console")]
+    pub use super::DecayConsolePlugin;
+
+    #[cfg(feature = "leafwing_abilities")]
+    pub use super::DecayCooldownCommandsExt;
+
+    #[cfg(feature = "persistence")]
+    pub use super::{DecayPersistencePlugin, DecaySnapshotRequested, DecayStore, DecayStoreBatch};
+
+    #[cfg(feature = "rapier")]
+    pub use super::{DecayRestMode, DecayWhenAtRest};
+
+    #[cfg(feature = "ron")]
+    pub use super::{DecaySnapshotExported, DecaySnapshotMigrations};
+
+    #[cfg(feature = "seldom_state")]
+    pub use super::{decay_fraction_at_least, DecayStateMachinePlugin};
+
+    #[cfg(feature = "serde")]
+    pub use super::{DecaySnapshot, DecaySnapshotEntry};
+
+    #[cfg(feature = "text")]
+    pub use super::DecayCountdownText;
+
+    #[cfg(feature = "tilemap")]
+    pub use super::DecayTileAction;
+
+    #[cfg(feature = "time_runner")]
+    pub use super::DecayTimeRunner;
 }
 
-/// Predefined set for systems handling decaying entities.
+/// Predefined sets for systems handling decaying entities.
 ///
-/// This system set groups together systems that operate on entities with the `Decay` component.
-/// It is typically used to control the execution order of decay-related systems, ensuring
+/// These system sets group together systems that operate on entities with the `Decay` component.
+/// They are typically used to control the execution order of decay-related systems, ensuring
 /// that decay processes are updated consistently each frame.
+///
+/// `Tick` always runs before `Complete`, so user systems ordered between them can inspect
+/// finished timers (e.g. to rescue an entity) before the completion commands are queued.
 #[derive(SystemSet, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
-pub struct DecayingSet;
+pub enum DecayingSet {
+    /// Systems progressing the decay timers.
+    Tick,
+    /// Systems handling entities whose decay timer has finished.
+    Complete,
+}
+
+/// System set for the systems of a single decay track.
+///
+/// Every `DecayPlugin<T>` places its systems in the `DecayTrackSet<T>` of its track, on top of
+/// the shared `DecayingSet` phases, so systems can be ordered against one track only.
+#[derive(SystemSet)]
+pub struct DecayTrackSet<T: Component>(PhantomData<T>);
+
+impl<T: Component> Default for DecayTrackSet<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Component> Clone for DecayTrackSet<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Component> Copy for DecayTrackSet<T> {}
+
+impl<T: Component> PartialEq for DecayTrackSet<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T: Component> Eq for DecayTrackSet<T> {}
+
+impl<T: Component> Hash for DecayTrackSet<T> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl<T: Component> fmt::Debug for DecayTrackSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DecayTrackSet<{}>", std::any::type_name::<T>())
+    }
+}
 
 /// Plugin that manages the decay system, allowing entities to decay over time.
 ///
@@ -22,20 +278,549 @@ pub struct DecayingSet;
 /// for managing the decay lifecycle of entities. It adds systems to handle starting,
 /// pausing, and completing the decay process, as well as managing the timers associated
 /// with decaying entities.
-pub struct DecayPlugin;
+///
+/// The plugin is generic over the marker component `T` of a decay track, which defaults to
+/// `Decay`. Several plugins can be added for different markers, each running an independent
+/// decay domain (e.g. world items and UI toasts) with its own timers, events, system set and
+/// configuration. Use `DecayPlugin::default()` for the `Decay` track and
+/// `DecayPlugin::<T>::new()` for any other track.
+///
+/// The observers starting and pausing the decay can be left out with
+/// [`DecayPlugin::without_observers`], in which case [`handle_decay_start`] and
+/// [`handle_decay_pause`] may be registered (or replaced) by the user. The systems ticking and
+/// completing the decay ([`decaying`], [`tick_decay_delays`], [`hold_group_barriers`],
+/// [`complete_decay`] and [`clear_completion_guard`]) are public as well, so they can be placed
+/// in custom schedules or sub-apps.
+pub struct DecayPlugin<T: Component = Decay> {
+    /// Whether the built-in observers starting and pausing the decay are registered.
+    pub observers: bool,
+    /// Whether the completion actions of entities (`DecayAction`, `DecayContents`,
+    /// `OnDecayCommand`, `OnDecaySpawn` and `DecayTileAction`) are executed when this track
+    /// completes.
+    pub actions: bool,
+    /// Whether the `DecayExpirationHistogram` of this track is updated.
+    pub expiration_histogram: bool,
+    /// Whether the `DecayTrackMetrics` of this track are updated.
+    pub metrics: bool,
+    /// Whether the `DecayOvershootHistogram` of this track records the completions.
+    pub overshoot_histogram: bool,
+    /// Whether decay starts are coalesced into a single `DecayStartedBatch` per frame instead
+    /// of triggering a `DecayStarted` event for each entity.
+    pub batch_started: bool,
+    /// The interval of the background scan scheduling the completions, if enabled.
+    pub async_scan: Option<Duration>,
+    /// The remaining time above which decays are ticked by the long lane, and the interval
+    /// between its checks, if enabled.
+    pub long_lane: Option<(Duration, Duration)>,
+    /// The maximum number of decays completing each frame, if limited.
+    pub completion_budget: Option<usize>,
+    /// The number of recent decay events retained by the `DecayHistory`, if enabled.
+    pub history: Option<usize>,
+    /// The maximum number of decays running at once and the order of the pending ones, if
+    /// capped.
+    pub concurrency_cap: Option<(usize, DecayQueueOrder)>,
+    /// Registrations of the extensions of this track, such as chained tracks.
+    extensions: Vec<fn(&mut App)>,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+// `Default` is only implemented for the `Decay` track, so `DecayPlugin::default()` can be
+// inferred without naming the track.
+#[allow(clippy::new_without_default)]
+impl<T: Component> DecayPlugin<T> {
+    /// Creates a new `DecayPlugin` for the decay track of the marker component `T`.
+    pub fn new() -> Self {
+        Self {
+            observers: true,
+            actions: true,
+            expiration_histogram: false,
+            metrics: false,
+            overshoot_histogram: false,
+            batch_started: false,
+            async_scan: None,
+            long_lane: None,
+            completion_budget: None,
+            history: None,
+            concurrency_cap: None,
+            extensions: Vec::new(),
+            track: PhantomData,
+        }
+    }
+
+    /// Only registers the ticking core, without the observers starting and pausing the decay.
+    pub fn without_observers(mut self) -> Self {
+        self.observers = false;
+        self
+    }
+
+    /// Does not execute the completion actions of entities (`DecayAction`, `DecayContents`,
+    /// `OnDecayCommand` and `OnDecaySpawn`) when this track completes.
+    ///
+    /// Useful for intermediate tracks of a chain, where the action should only run once the
+    /// final track completes.
+    pub fn without_actions(mut self) -> Self {
+        self.actions = false;
+        self
+    }
+
+    /// Updates the `DecayExpirationHistogram` of this track every frame.
+    ///
+    /// The default buckets are used unless the resource is inserted beforehand.
+    pub fn with_expiration_histogram(mut self) -> Self {
+        self.expiration_histogram = true;
+        self
+    }
+
+    /// Updates the `DecayTrackMetrics` of this track every frame, measuring how many entities
+    /// decay in each archetype and how long ticking each of them took.
+    pub fn with_metrics(mut self) -> Self {
+        self.metrics = true;
+        self
+    }
+
+    /// Records how late each completion of this track fired relative to its deadline into the
+    /// `DecayOvershootHistogram`.
+    ///
+    /// The default buckets are used unless the resource is inserted beforehand.
+    pub fn with_overshoot_histogram(mut self) -> Self {
+        self.overshoot_histogram = true;
+        self
+    }
+
+    /// Coalesces the decay starts of a frame into a single `DecayStartedBatch` event, instead
+    /// of triggering a `DecayStarted` event for each entity.
+    ///
+    /// This keeps mass spawns of decaying entities cheap for observers.
+    pub fn with_batched_started(mut self) -> Self {
+        self.batch_started = true;
+        self
+    }
+
+    /// Schedules the completions of this track from a scan running in the background every
+    /// `interval`, instead of checking every decaying entity each frame.
+    ///
+    /// Meant for worlds with huge numbers of long-lived decays: the remaining times are sorted
+    /// into deadlines by an `AsyncComputeTaskPool` task, and the main thread only checks the
+    /// entities whose deadline has passed. Decays that start, resume or speed up after a scan
+    /// may complete up to one interval late.
+    pub fn with_async_scan(mut self, interval: Duration) -> Self {
+        self.async_scan = Some(interval);
+        self
+    }
+
+    /// Ticks the decays of this track with more than `threshold` remaining (e.g. days) every
+    /// `interval` (e.g. a few seconds) from the `DecayLongLane`, instead of every frame.
+    ///
+    /// Decays return to per-frame ticking as they near completion, so they still complete on
+    /// time, while their timers lag behind by up to one interval in the meantime.
+    pub fn with_long_lane(mut self, threshold: Duration, interval: Duration) -> Self {
+        self.long_lane = Some((threshold, interval));
+        self
+    }
+
+    /// Limits the decays of this track completing each frame, deferring the others to the next
+    /// frames by `DecayPriority`.
+    ///
+    /// The budget is stored in the `DecayCompletionBudget` resource of the track.
+    pub fn with_completion_budget(mut self, max_per_frame: usize) -> Self {
+        self.completion_budget = Some(max_per_frame);
+        self
+    }
+
+    /// Retains the last `capacity` started, paused and completed events of this track in the
+    /// `DecayHistory` resource.
+    pub fn with_history(mut self, capacity: usize) -> Self {
+        self.history = Some(capacity);
+        self
+    }
+
+    /// Caps the decays of this track running at once, queueing the new ones beyond the cap
+    /// until slots free up, in the given order.
+    ///
+    /// The cap is stored in the `DecayConcurrency` resource of the track.
+    pub fn with_concurrency_cap(mut self, max_active: usize, order: DecayQueueOrder) -> Self {
+        self.concurrency_cap = Some((max_active, order));
+        self
+    }
+
+    /// Decides how this track decays while entities bear the disabling marker component `D`:
+    /// keep ticking, skip the span spent disabled, or skip it and catch up on it at once when
+    /// the marker is removed.
+    ///
+    /// Skipped decays bear a `DecayDisabled<T>` while disabled, leaving the other tracks of the
+    /// entity untouched. The span is measured in virtual time, and is applied through the same
+    /// path as [`fast_forward_decay`](DecayCommandsExt::fast_forward_decay); decays still
+    /// paused once enabled are not caught up, as they would not have progressed either.
+    ///
+    /// The marker is chosen by the app, as the pinned Bevy revision has no built-in `Disabled`
+    /// component to rely on.
+    pub fn with_disabling<D: Component>(mut self, policy: DecayWhileDisabled) -> Self {
+        match policy {
+            DecayWhileDisabled::KeepTicking => {}
+            DecayWhileDisabled::Skip => self.extensions.push(|app| {
+                app.add_observer(disabled::skip_disabled::<D, T>)
+                    .add_observer(disabled::skip_started_disabled::<D, T>)
+                    .add_observer(disabled::resume_enabled::<D, T>);
+            }),
+            DecayWhileDisabled::CatchUp => self.extensions.push(|app| {
+                app.add_observer(disabled::skip_disabled::<D, T>)
+                    .add_observer(disabled::skip_started_disabled::<D, T>)
+                    .add_observer(disabled::catch_up_enabled::<D, T>);
+            }),
+        }
+        self
+    }
+
+    /// Handles `DecayThen<T, To>`, starting the `To` track once this track completes.
+    pub fn then<To: Component>(mut self) -> Self {
+        self.extensions.push(|app| {
+            app.add_observer(chain::handle_decay_then::<T, To>);
+        });
+        self
+    }
+
+    /// Handles `StartDecayOn<C, T>`, starting this track once the component `C` is added to an
+    /// entity.
+    pub fn start_on<C: Component>(mut self) -> Self {
+        self.extensions.push(|app| {
+            app.add_observer(on_component::start_decay_on_add::<C, T>)
+                .add_observer(on_component::start_decay_on_insert::<C, T>);
+        });
+        self
+    }
+
+    /// Handles `CancelDecayOn<C, T>`, cancelling this track once the component `C` is added to
+    /// an entity.
+    pub fn cancel_on<C: Component>(mut self) -> Self {
+        self.extensions.push(|app| {
+            app.add_observer(on_component::cancel_decay_on_add::<C, T>)
+                .add_observer(on_component::cancel_decay_on_insert::<C, T>);
+        });
+        self
+    }
+
+    /// Cancels the decay of this track on the descendants bearing `InheritDecayState` whenever
+    /// the decay of their ancestor is cancelled or aborted.
+    pub fn with_cascade_cancel(mut self) -> Self {
+        self.extensions.push(|app| {
+            app.add_observer(cascade::cascade_decay_cancel::<T>);
+        });
+        self
+    }
+
+    /// Handles `RefreshDecayOnChange<C>`, refreshing the decay of this track whenever the
+    /// component `C` of an entity changes.
+    pub fn refresh_on_change<C: Component>(mut self) -> Self {
+        self.extensions.push(|app| {
+            app.add_systems(
+                PreUpdate,
+                refresh::refresh_decay_on_change::<C, T>
+                    .after(decaying::<T>)
+                    .before(complete_decay::<T>)
+                    .in_set(DecayTrackSet::<T>::default()),
+            );
+        });
+        self
+    }
+
+    /// Re-rolls a fresh duration from `DecayDuration` whenever the decay of this track resumes,
+    /// for every entity rather than only those with `RerollDecayOnResume`.
+    ///
+    /// Matches designs where picking up and dropping an item resets its rot.
+    pub fn with_reroll_on_resume(mut self) -> Self {
+        self.extensions.push(|app| {
+            app.add_observer(refresh::discard_decay_timer::<T, ()>);
+        });
+        self
+    }
+
+    /// Handles `DecayField<C, L, T>`, interpolating a field of the component `C` through the
+    /// lens `L` as this track progresses.
+    pub fn with_field<C: Component, L: DecayLens<C>>(mut self) -> Self {
+        self.extensions.push(|app| {
+            app.add_systems(
+                PreUpdate,
+                lens::apply_decay_fields::<C, L, T>
+                    .after(decaying::<T>)
+                    .in_set(DecayingSet::Tick)
+                    .in_set(DecayTrackSet::<T>::default()),
+            );
+        });
+        self
+    }
+
+    /// Handles `OnDecayTrigger<E>`, triggering the stored event once this track completes.
+    pub fn trigger_on_decay<E: Event>(mut self) -> Self {
+        self.extensions.push(|app| {
+            app.add_observer(action::handle_decay_trigger::<E, T>);
+        });
+        self
+    }
+
+    /// Rolls the `DecayLoot` of entities bearing the component `L` once this track completes.
+    pub fn with_loot<L: DecayLoot>(mut self) -> Self {
+        self.extensions.push(|app| {
+            app.add_observer(action::handle_decay_loot::<L, T>);
+        });
+        self
+    }
+
+    /// Triggers `DecayCompletedOf<M, T>` with the entities bearing the marker component `M`
+    /// whenever this track completes.
+    pub fn with_completed_of<M: Component>(mut self) -> Self {
+        self.extensions.push(register_completed_of::<M, T>);
+        self
+    }
+}
+
+impl Default for DecayPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Component> Plugin for DecayPlugin<T> {
+    fn build(&self, app: &mut App) {
+        // The systems shared by every track are only registered once.
+        if !app.is_plugin_added::<DecayCorePlugin>() {
+            app.add_plugins(DecayCorePlugin);
+        }
+
+        app.add_event::<DecayStarted<T>>()
+            .add_event::<DecayPaused<T>>()
+            .add_event::<DecayCompleted<T>>()
+            .add_event::<DecayStackConsumed<T>>()
+            .add_event::<DecayStartedBatch<T>>()
+            .add_event::<DecayTimerOrphaned<T>>();
+
+        app.init_resource::<DecayCompletionGuard<T>>()
+            .add_systems(First, clear_completion_guard::<T>);
+
+        app.add_systems(
+            Last,
+            cleanup::remove_orphaned_timers::<T>.in_set(DecayTrackSet::<T>::default()),
+        );
+
+        app.init_resource::<DecayBarriers<T>>()
+            .add_observer(group::trigger_group_completed::<T>);
+
+        app.init_resource::<DecayLodSchedule<T>>()
+            .add_observer(lod::stop_waiting_on_pause::<T>)
+            .add_observer(lod::stop_waiting_on_freeze::<T>)
+            .add_observer(lod::stop_waiting_on_disable::<T>)
+            .add_observer(lod::stop_waiting_on_removal::<T>);
+
+        app.init_resource::<simulate::DecayTracks>()
+            .world_mut()
+            .resource_mut::<simulate::DecayTracks>()
+            .register::<T>();
+
+        app.add_systems(
+            PreUpdate,
+            (
+                decaying::<T>.in_set(DecayingSet::Tick),
+                tick_decay_delays::<T>.in_set(DecayingSet::Tick),
+                wall_clock::reconcile_wall_clock::<T>
+                    .after(decaying::<T>)
+                    .in_set(DecayingSet::Tick),
+                wall_clock::reconcile_decay_at::<T>
+                    .after(decaying::<T>)
+                    .before(hold_group_barriers::<T>)
+                    .in_set(DecayingSet::Tick),
+                hold_group_barriers::<T>
+                    .after(decaying::<T>)
+                    .in_set(DecayingSet::Tick),
+                sprite::update_stage_sprites::<T>
+                    .after(decaying::<T>)
+                    .in_set(DecayingSet::Tick),
+                pause::report_global_pause::<T>
+                    .before(decaying::<T>)
+                    .in_set(DecayingSet::Tick),
+                complete_decay::<T>.in_set(DecayingSet::Complete),
+            )
+                .in_set(DecayTrackSet::<T>::default()),
+        );
+
+        if self.actions {
+            app.add_observer(handle_decay_action::<T>)
+                .add_observer(action::handle_decay_command::<T>)
+                .add_observer(action::handle_decay_spawn::<T>);
+
+            #[cfg(feature = "tilemap")]
+            app.add_observer(tilemap::handle_decay_tile_action::<T>);
+        }
+
+        if self.expiration_histogram {
+            app.init_resource::<DecayExpirationHistogram<T>>()
+                .add_systems(
+                    PreUpdate,
+                    diagnostic::update_expiration_histogram::<T>
+                        .after(decaying::<T>)
+                        .in_set(DecayTrackSet::<T>::default()),
+                );
+        }
+
+        if self.metrics {
+            app.init_resource::<DecayTrackMetrics<T>>().add_systems(
+                PreUpdate,
+                diagnostic::update_track_metrics::<T>
+                    .after(decaying::<T>)
+                    .in_set(DecayTrackSet::<T>::default()),
+            );
+        }
+
+        if self.overshoot_histogram {
+            app.init_resource::<DecayOvershootHistogram<T>>()
+                .add_observer(diagnostic::record_completion_overshoot::<T>)
+                .add_observer(diagnostic::prune_overshoot_deadline::<T>);
+        }
+
+        #[cfg(feature = "text")]
+        app.add_systems(
+            PreUpdate,
+            text::update_countdown_texts::<T>
+                .after(decaying::<T>)
+                .in_set(DecayTrackSet::<T>::default()),
+        );
+
+        #[cfg(feature = "time_runner")]
+        app.add_systems(
+            PreUpdate,
+            time_runner::sync_time_runners::<T>
+                .after(decaying::<T>)
+                .before(hold_group_barriers::<T>)
+                .in_set(DecayingSet::Tick)
+                .in_set(DecayTrackSet::<T>::default()),
+        );
+
+        #[cfg(feature = "metrics")]
+        app.add_observer(telemetry::count_completions::<T>)
+            .add_observer(telemetry::count_cancellations::<T>)
+            .add_systems(
+                Last,
+                telemetry::report_active_decays::<T>.in_set(DecayTrackSet::<T>::default()),
+            );
+
+        if let Some(max_per_frame) = self.completion_budget {
+            app.insert_resource(DecayCompletionBudget::<T>::new(max_per_frame));
+        }
+
+        if let Some((max_active, order)) = self.concurrency_cap {
+            app.insert_resource(DecayConcurrency::<T>::new(max_active, order))
+                .add_systems(
+                    PreUpdate,
+                    budget::start_pending_decays::<T>
+                        .after(complete_decay::<T>)
+                        .in_set(DecayingSet::Complete)
+                        .in_set(DecayTrackSet::<T>::default()),
+                );
+        }
+
+        if let Some(capacity) = self.history {
+            app.insert_resource(DecayHistory::<T>::new(capacity))
+                .add_observer(history::record_started::<T>)
+                .add_observer(history::record_started_batch::<T>)
+                .add_observer(history::record_paused::<T>)
+                .add_observer(history::record_completed::<T>);
+        }
+
+        if let Some(interval) = self.async_scan {
+            app.insert_resource(scan::DecayScan::<T>::new(interval))
+                .add_systems(
+                    PreUpdate,
+                    scan::scan_decay_deadlines::<T>
+                        .after(decaying::<T>)
+                        .in_set(DecayingSet::Tick)
+                        .in_set(DecayTrackSet::<T>::default()),
+                );
+        }
+
+        if let Some((threshold, interval)) = self.long_lane {
+            app.insert_resource(lane::DecayLongLane::<T>::new(threshold, interval))
+                .add_systems(
+                    PreUpdate,
+                    lane::check_long_lane::<T>
+                        .after(decaying::<T>)
+                        .in_set(DecayingSet::Tick)
+                        .in_set(DecayTrackSet::<T>::default()),
+                )
+                .add_observer(lane::leave_long_lane_on_pause::<T>)
+                .add_observer(lane::leave_long_lane_on_freeze::<T>)
+                .add_observer(lane::leave_long_lane_on_disable::<T>);
+        }
+
+        if self.batch_started {
+            app.init_resource::<DecayStartedBuffer<T>>().add_systems(
+                Last,
+                flush_started_batch::<T>.in_set(DecayTrackSet::<T>::default()),
+            );
+        }
 
-impl Plugin for DecayPlugin {
+        for extension in &self.extensions {
+            extension(app);
+        }
+
+        if self.observers {
+            #[cfg(not(feature = "require-duration"))]
+            app.add_observer(handle_decay_duration_added::<T>);
+
+            app.add_observer(handle_decay_start::<T>)
+                .add_observer(after::release_decay_after::<T>)
+                .add_observer(after::handle_decay_after_released::<T>)
+                .add_observer(handle_decay_pause::<T>)
+                .add_observer(refresh::discard_decay_timer::<T, With<RerollDecayOnResume>>)
+                .add_observer(handle_decay_freeze::<T>)
+                .add_observer(handle_decay_thaw::<T>);
+        }
+    }
+}
+
+/// Plugin registering the sets and systems shared by every decay track.
+struct DecayCorePlugin;
+
+impl Plugin for DecayCorePlugin {
     fn build(&self, app: &mut App) {
-        app.configure_sets(PreUpdate, DecayingSet);
+        app.configure_sets(
+            PreUpdate,
+            (DecayingSet::Tick, DecayingSet::Complete).chain(),
+        );
 
-        app.add_event::<DecayStarted>()
-            .add_event::<DecayPaused>()
-            .add_event::<DecayCompleted>();
+        app.add_systems(
+            PreUpdate,
+            (
+                rate::expire_rate_effects,
+                idle::track_idle,
+                region::apply_decay_regions.run_if(region::any_decay_region),
+                lod::apply_decay_lod_policy.run_if(resource_exists::<DecayLodPolicy>),
+            )
+                .in_set(DecayingSet::Tick),
+        );
 
-        app.add_systems(PreUpdate, decaying.in_set(DecayingSet));
+        app.add_systems(
+            PreUpdate,
+            despawn::despawn_decayed
+                .run_if(resource_exists::<DecayDespawner>)
+                .after(DecayingSet::Complete),
+        );
 
-        app.add_observer(handle_decay_start)
-            .add_observer(handle_decay_pause);
+        app.add_systems(
+            First,
+            focus::track_window_focus.run_if(resource_exists::<DecayFocusSuspend>),
+        );
+
+        app.register_type::<DecayDuration>();
+
+        app.add_observer(pause::handle_pause_guard_inserted)
+            .add_observer(pause::handle_pause_guard_replaced)
+            .add_observer(group::pause_group_members)
+            .add_observer(group::resume_group_members)
+            .add_observer(group::handle_group_joined)
+            .add_observer(group::handle_group_left);
+
+        #[cfg(feature = "rapier")]
+        app.add_systems(PreUpdate, rapier::apply_rest_gate.in_set(DecayingSet::Tick))
+            .add_observer(rapier::handle_rest_gate_removed);
     }
 }
 
@@ -43,22 +828,84 @@ impl Plugin for DecayPlugin {
 ///
 /// This component indicates that an entity is subject to a decay process.
 /// It is used in conjunction with the `DecayDuration` component to manage
-/// the lifecycle of decaying entities within the world.
-#[derive(Component, Debug)]
-#[require(DecayDuration)]
+/// the lifecycle of decaying entities within the world. It is the marker of the
+/// default decay track; other tracks use their own marker components.
+///
+/// With the `require-duration` feature (enabled by default), a zero `DecayDuration` is inserted
+/// alongside this component when missing. Without it, the decay starts once a `DecayDuration`
+/// is provided, which is useful when durations are managed by a user registry.
+///
+/// With the `sparse-timers` feature, it is stored in a sparse set along with the timers, so a
+/// decay cycle does not move the entity between tables. Markers of other tracks may opt in with
+/// `#[component(storage = "SparseSet")]`.
+#[derive(Component, Default, Debug)]
+#[cfg_attr(feature = "require-duration", require(DecayDuration))]
+#[cfg_attr(feature = "sparse-timers", component(storage = "SparseSet"))]
 pub struct Decay;
 
+impl Decay {
+    /// Creates a ready-to-spawn bundle decaying over a fixed duration.
+    pub fn for_duration(duration: Duration) -> (Self, DecayDuration) {
+        (Self, DecayDuration::new(duration))
+    }
+
+    /// Creates a ready-to-spawn bundle decaying over a random duration between `min` and `max`.
+    pub fn for_range(min: Duration, max: Duration) -> (Self, DecayDuration) {
+        (Self, DecayDuration::randomized(min, max))
+    }
+}
+
 /// Stores the duration for decay, with a minimum and maximum time range.
 ///
-/// This component is utilized by the `Decay` component to define the range within which the
-/// entity will decay. The actual decay duration is randomized between the specified `min`
-/// and `max` values to introduce variability in decay times.
-#[derive(Component, Default, Debug)]
+/// This component is utilized by the `Decay` component (or the marker of any other track) to
+/// define the range within which the entity will decay. The actual decay duration is randomized
+/// between the specified `min` and `max` values to introduce variability in decay times.
+///
+/// With the `compact-duration` feature, the bounds are stored as whole milliseconds in a `u32`,
+/// shrinking the component from 32 to 8 bytes at the cost of saturating at about 49 days.
+///
+/// It is registered for reflection, so inspectors can edit it, and its `Display` renders the
+/// range with units (e.g. `"1m - 1m 30s"`) for editor and debug UIs.
+#[derive(Component, Reflect, Default, Clone, Copy, Debug)]
+#[reflect(Component, Default, Debug)]
 pub struct DecayDuration {
     /// The minimum duration for decay.
-    min: Duration,
+    min: DurationBound,
     /// The maximum duration for decay.
-    max: Duration,
+    max: DurationBound,
+}
+
+/// Storage of the bounds of a `DecayDuration`.
+#[cfg(not(feature = "compact-duration"))]
+type DurationBound = Duration;
+
+/// Storage of the bounds of a `DecayDuration`, in whole milliseconds.
+#[cfg(feature = "compact-duration")]
+type DurationBound = u32;
+
+/// Converts a duration into the storage of a `DecayDuration` bound.
+#[cfg(not(feature = "compact-duration"))]
+fn to_bound(duration: Duration) -> DurationBound {
+    duration
+}
+
+/// Converts a duration into the storage of a `DecayDuration` bound, saturating at `u32::MAX`
+/// milliseconds.
+#[cfg(feature = "compact-duration")]
+fn to_bound(duration: Duration) -> DurationBound {
+    duration.as_millis().try_into().unwrap_or(u32::MAX)
+}
+
+/// Converts the storage of a `DecayDuration` bound back into a duration.
+#[cfg(not(feature = "compact-duration"))]
+fn from_bound(bound: DurationBound) -> Duration {
+    bound
+}
+
+/// Converts the storage of a `DecayDuration` bound back into a duration.
+#[cfg(feature = "compact-duration")]
+fn from_bound(bound: DurationBound) -> Duration {
+    Duration::from_millis(bound.into())
 }
 
 impl DecayDuration {
@@ -72,10 +919,33 @@ impl DecayDuration {
     /// If the provided `min` duration is greater than the `max` duration,
     /// the values are swapped to ensure valid range.
     pub fn randomized(min: Duration, max: Duration) -> Self {
-        if min > max {
-            Self { min: max, max: min }
+        let (min, max) = if min > max { (max, min) } else { (min, max) };
+
+        Self {
+            min: to_bound(min),
+            max: to_bound(max),
+        }
+    }
+
+    /// Returns the minimum duration for decay.
+    pub fn min(&self) -> Duration {
+        from_bound(self.min)
+    }
+
+    /// Returns the maximum duration for decay.
+    pub fn max(&self) -> Duration {
+        from_bound(self.max)
+    }
+
+    /// Rolls a duration within the range with the given random number generator, or returns
+    /// the fixed duration if `min` and `max` are the same.
+    pub fn roll(&self, rng: &mut impl Rng) -> Duration {
+        let (min, max) = (self.min(), self.max());
+        if min == max {
+            min
         } else {
-            Self { min, max }
+            let random_millis = rng.gen_range(min.as_millis()..=max.as_millis());
+            Duration::from_millis(random_millis as u64)
         }
     }
 
@@ -83,7 +953,23 @@ impl DecayDuration {
     ///
     /// Returns `true` if both `min` and `max` durations are zero; otherwise, `false`.
     pub fn is_zero(&self) -> bool {
-        self.min == Duration::ZERO && self.max == Duration::ZERO
+        self.min() == Duration::ZERO && self.max() == Duration::ZERO
+    }
+}
+
+impl fmt::Display for DecayDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = DurationFormat::default();
+        if self.min == self.max {
+            f.write_str(&format.format(self.min()))
+        } else {
+            write!(
+                f,
+                "{} - {}",
+                format.format(self.min()),
+                format.format(self.max())
+            )
+        }
     }
 }
 
@@ -95,142 +981,1298 @@ impl DecayDuration {
 /// process and making it less predictable.
 impl From<&DecayDuration> for Duration {
     fn from(duration: &DecayDuration) -> Self {
-        if duration.min == duration.max {
-            duration.min
-        } else {
-            let mut rng = rand::thread_rng();
-            let random_millis = rng.gen_range(duration.min.as_millis()..=duration.max.as_millis());
-            Duration::from_millis(random_millis as u64)
-        }
+        duration.roll(&mut rand::thread_rng())
     }
 }
 
-/// A timer component used for counting down the decay time.
+/// Marker component freezing the decay of an entity in place.
 ///
-/// When attached to an entity, this timer counts down and signals when the decay is complete.
-#[derive(Component, Default, Deref, DerefMut, Debug)]
-struct DecayTimer(Timer);
-
-impl DecayTimer {
-    /// Creates a new `DecayTimer` with the given duration. The timer is set to run once.
-    pub fn new(duration: Duration) -> Self {
-        Self(Timer::new(duration, TimerMode::Once))
-    }
-}
-
-/// Event triggered when the decay process starts for an entity.
+/// While present, the decay timers of all tracks of the entity are paused without removing
+/// their markers. Removing it resumes the decay where it was left.
 ///
-/// This event is dispatched when an entity with a `Decay` component begins the decay process.
-/// It provides the entity that is decaying and the duration for which the decay will last.
-#[derive(Event)]
-pub struct DecayStarted {
-    /// The entity that has started decaying.
-    pub entity: Entity,
-    /// The duration for which the entity will decay.
-    pub duration: Duration,
-}
+/// When several systems may pause the same entity, prefer the `pause_decay` and `resume_decay`
+/// commands, which only remove it once every source released its pause (see
+/// `DecayPauseSources`).
+#[derive(Component, Default, Debug)]
+pub struct DecayFrozen;
 
-/// Event triggered when the decay process is paused for an entity.
+/// Marker component hard-blocking the decay of an entity, such as quest items or props spawned
+/// by game masters.
 ///
-/// This event is fired when an entity with an active decay timer has its decay paused.
-/// It includes the entity and the remaining duration of the decay at the time of pausing.
-#[derive(Event)]
-pub struct DecayPaused {
-    /// The entity that has paused its decay process.
-    pub entity: Entity,
-    /// The remaining duration of decay when the process was paused.
-    pub remaining_duration: Duration,
-}
+/// While present, decay neither starts nor ticks on any track of the entity, regardless of its
+/// other components, and it takes precedence over any system attaching decay automatically.
+/// Removing it lets already started timers tick again, while a decay blocked from starting
+/// begins once its track marker is inserted again.
+#[derive(Component, Default, Debug)]
+pub struct NeverDecay;
 
-/// Event triggered when the decay process is completed for an entity.
+/// Query filter for entities actively decaying on the track `T` (`Decay` by default).
 ///
-/// This event is sent when an entity's decay timer has finished and the decay process is complete.
-/// The event contains the entities that have completed their decay.
-#[derive(Event, Deref, DerefMut)]
-pub struct DecayCompleted(pub Vec<Entity>);
-
-/// System that handles the initiation of decay for entities when the `Decay` component is added.
-fn handle_decay_start(
-    trigger: Trigger<OnAdd, Decay>,
-    mut commands: Commands,
-    mut query: Query<(Entity, &DecayDuration, Option<&mut DecayTimer>)>,
-) {
-    let Ok((entity, decay_duration, decay_timer)) = query.get_mut(trigger.entity()) else {
-        return;
-    };
+/// Matches entities with the track marker and its timer that are neither frozen nor blocked by
+/// `NeverDecay`.
+pub type Decaying<T = Decay> = (
+    With<T>,
+    With<DecayTimer<T>>,
+    Without<DecayFrozen>,
+    Without<NeverDecay>,
+);
 
-    // If the decay duration is zero, remove the `Decay` and `DecayTimer` components immediately.
-    if decay_duration.is_zero() {
-        commands
-            .entity(entity)
-            .remove::<Decay>()
-            .remove::<DecayTimer>();
-    }
-    // If a timer already exists, unpause it.
-    else if let Some(mut timer) = decay_timer {
-        timer.unpause();
+/// Query filter for entities whose decay on the track `T` (`Decay` by default) started since
+/// the last time the system ran.
+///
+/// Only the first start of a decay is matched; resuming a paused decay keeps the same timer.
+pub type RecentlyStartedDecay<T = Decay> = (With<T>, Added<DecayTimer<T>>);
 
-        // Trigger the `DecayStarted` event with the remaining duration.
-        commands.trigger(DecayStarted {
-            entity,
-            duration: timer.remaining(),
-        });
-    }
-    // If no timer exists, create a new timer with a duration and start the decay process.
-    else {
-        let duration = Duration::from(decay_duration);
-        commands.entity(entity).insert(DecayTimer::new(duration));
+/// Elapsed offset the decay of an entity starts with.
+///
+/// When the decay timer is created, it starts already partially elapsed instead of at zero,
+/// e.g. when loading saved items or receiving network state. The component is consumed once
+/// the decay has started; resuming a paused decay is not affected.
+#[derive(Component, Clone, Copy, Debug)]
+pub enum DecayStartOffset {
+    /// The decay starts with the given elapsed time, capped at the rolled duration.
+    Elapsed(Duration),
+    /// The decay starts with the given fraction of the rolled duration elapsed, from 0 to 1.
+    Fraction(f32),
+}
 
-        // Trigger the `DecayStarted` event with the duration.
-        commands.trigger(DecayStarted { entity, duration });
+impl DecayStartOffset {
+    /// Returns the elapsed time the decay starts with, for the given rolled duration.
+    pub fn elapsed(&self, duration: Duration) -> Duration {
+        match *self {
+            DecayStartOffset::Elapsed(elapsed) => elapsed.min(duration),
+            DecayStartOffset::Fraction(fraction) => duration.mul_f32(fraction.clamp(0.0, 1.0)),
+        }
     }
 }
 
-/// System that handles pausing decay for entities when the `Decay` component is removed.
-fn handle_decay_pause(
-    trigger: Trigger<OnRemove, Decay>,
-    mut commands: Commands,
-    mut query: Query<(Entity, &mut DecayTimer)>,
-) {
-    if let Ok((entity, mut timer)) = query.get_mut(trigger.entity()) {
-        // Pause the decay timer for the entity.
-        timer.pause();
+/// Grace period before the decay of an entity begins.
+///
+/// When the decay is started, its timer only starts counting after the delay has elapsed, and
+/// the `DecayStarted` event is triggered at that moment. Useful for rules like items being
+/// safe for 60 seconds after being dropped. The delay is paused along with the decay and is
+/// consumed once the decay has started.
+#[derive(Component, Clone, Copy, Deref, DerefMut, Debug)]
+pub struct DecayDelay(pub Duration);
 
-        // Send a `DecayPaused` event, including the remaining duration.
-        commands.trigger(DecayPaused {
-            entity,
-            remaining_duration: timer.remaining(),
-        });
+/// A timer counting down the `DecayDelay` before the decay of the track `T` begins.
+///
+/// It is managed by the plugin, and only public so [`tick_decay_delays`] can be scheduled
+/// manually.
+#[derive(Component, Deref, DerefMut)]
+#[cfg_attr(feature = "sparse-timers", component(storage = "SparseSet"))]
+pub struct DecayDelayTimer<T: Component = Decay>(#[deref] Timer, PhantomData<T>);
+
+impl<T: Component> DecayDelayTimer<T> {
+    /// Creates a new `DecayDelayTimer` with the given delay.
+    fn new(delay: Duration) -> Self {
+        Self(Timer::new(delay, TimerMode::Once), PhantomData)
     }
 }
 
-/// System that processes decaying entities by ticking their timers.
-fn decaying(
-    time: Res<Time>,
-    mut commands: Commands,
-    mut query: Query<(Entity, &mut DecayTimer), With<Decay>>,
-) {
-    let mut decayed_entities = vec![];
+/// Number of times an entity decays before its decay process is completed.
+///
+/// Each time the decay timer finishes, one stack is consumed and the timer is restarted with a
+/// freshly rolled duration from `DecayDuration`. Only the final stack completes the decay and
+/// triggers `DecayCompleted`, which suits stacked consumables and damage-over-time effects.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Deref, DerefMut, Debug)]
+pub struct DecayStacks(pub u32);
 
-    for (entity, mut timer) in query.iter_mut() {
-        // Progress the decay timer based on the time elapsed since the last frame.
-        timer.tick(time.delta());
+/// Number of charges an entity can use before its decay process is completed.
+///
+/// Charges are consumed with [`DecayEntityCommandsExt::consume_charge`]. The decay completes
+/// when either its timer finishes or the charges hit zero, whichever comes first, like rings
+/// decaying by time or by use.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Deref, DerefMut, Debug)]
+pub struct DecayCharges(pub u32);
+
+/// Lightweight tag copied into the `DecayStarted` and `DecayCompleted` events of an entity.
+///
+/// Lets observers branch on the category of an item (e.g. food, corpse or litter) without
+/// querying the entity for each event. Enums can be stored as their discriminant.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Hash, Deref, DerefMut, Debug)]
+pub struct DecayTag(pub u32);
+
+/// Action performed on an entity once its decay process is completed.
+///
+/// This component is read when the `DecayCompleted` event is triggered, allowing an entity to
+/// carry its own completion behavior instead of relying on an external observer.
+#[derive(Component, Default, Clone, Copy, Debug)]
+pub enum DecayAction {
+    /// Nothing happens; the entity simply stops decaying.
+    #[default]
+    None,
+    /// The entity is despawned, along with its children, or batched by the `DecayDespawner`.
+    Despawn,
+    /// The entity is transformed by the given function, e.g. replaced by another item.
+    Transform(fn(&mut EntityCommands)),
+}
+
+/// Bundle containing everything needed to spawn a decaying entity.
+///
+/// Groups the `Decay` marker, its `DecayDuration` and the `DecayAction` executed on completion,
+/// so a decaying item and its completion behavior can be spawned as a single struct.
+#[derive(Bundle, Default, Debug)]
+pub struct DecayBundle {
+    /// The marker starting the decay process.
+    pub decay: Decay,
+    /// The duration range for the decay.
+    pub duration: DecayDuration,
+    /// The action performed once the decay is completed.
+    pub on_complete: DecayAction,
+}
+
+impl DecayBundle {
+    /// Creates a new `DecayBundle` with the given duration and completion action.
+    pub fn new(duration: DecayDuration, on_complete: DecayAction) -> Self {
+        Self {
+            decay: Decay,
+            duration,
+            on_complete,
+        }
+    }
+}
+
+/// A timer component used for counting down the decay time of the track `T`.
+///
+/// When attached to an entity, this timer counts down and signals when the decay is complete.
+/// It is managed by the plugin, and other crates can build tooling on top of its read-only
+/// accessors ([`remaining`](Self::remaining), [`elapsed`](Self::elapsed),
+/// [`fraction`](Self::fraction) and [`paused`](Self::paused)), which account for the elapsed
+/// time held back in the timer. Timers waiting for their `DecayLod` or ticked by the
+/// `DecayLongLane` lag behind by up to one interval. The underlying `Timer` can be read through
+/// `Deref`, but timers can only be created and changed by the plugin and its commands.
+///
+/// With the `sparse-timers` feature, it is stored in a sparse set, so inserting and removing it
+/// does not move the entity between tables.
+#[derive(Component, Deref)]
+#[cfg_attr(feature = "sparse-timers", component(storage = "SparseSet"))]
+pub struct DecayTimer<T: Component = Decay> {
+    /// The timer counting down the decay.
+    #[deref]
+    timer: Timer,
+    /// The elapsed time accumulated and not yet applied to the timer.
+    pending: Duration,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayTimer<T> {
+    /// Creates a new `DecayTimer` with the given duration. The timer is set to run once.
+    pub(crate) fn new(duration: Duration) -> Self {
+        Self {
+            timer: Timer::new(duration, TimerMode::Once),
+            pending: Duration::ZERO,
+            track: PhantomData,
+        }
+    }
+
+    /// Returns the total duration of the decay.
+    pub fn duration(&self) -> Duration {
+        self.timer.duration()
+    }
+
+    /// Returns the time elapsed since the decay started.
+    pub fn elapsed(&self) -> Duration {
+        (self.timer.elapsed() + self.pending).min(self.timer.duration())
+    }
+
+    /// Returns the time left before the decay completes.
+    pub fn remaining(&self) -> Duration {
+        self.duration() - self.elapsed()
+    }
+
+    /// Returns the fraction of the decay that has elapsed, between `0.0` and `1.0`.
+    pub fn fraction(&self) -> f32 {
+        if self.duration().is_zero() {
+            1.0
+        } else {
+            self.elapsed().as_secs_f32() / self.duration().as_secs_f32()
+        }
+    }
+
+    /// Checks if the decay is paused.
+    pub fn paused(&self) -> bool {
+        self.timer.paused()
+    }
+
+    /// Advances the timer by the given elapsed time.
+    pub(crate) fn tick(&mut self, delta: Duration) {
+        self.timer.tick(delta);
+    }
+
+    /// Pauses the timer.
+    pub(crate) fn pause(&mut self) {
+        self.timer.pause();
+    }
+
+    /// Unpauses the timer.
+    pub(crate) fn unpause(&mut self) {
+        self.timer.unpause();
+    }
+
+    /// Resets the timer, so a finished timer can run again.
+    pub(crate) fn reset(&mut self) {
+        self.timer.reset();
+    }
+
+    /// Sets the total duration of the decay.
+    pub(crate) fn set_duration(&mut self, duration: Duration) {
+        self.timer.set_duration(duration);
+    }
+
+    /// Sets the time elapsed on the timer, keeping the elapsed time held back.
+    pub(crate) fn set_elapsed(&mut self, elapsed: Duration) {
+        self.timer.set_elapsed(elapsed);
+    }
+
+    /// Restarts the decay with the given elapsed time, discarding any elapsed time held back.
+    ///
+    /// The timer is reset first, so a finished timer can run again.
+    pub(crate) fn restart_at(&mut self, elapsed: Duration) {
+        self.timer.reset();
+        self.timer.set_elapsed(elapsed);
+        self.pending = Duration::ZERO;
+    }
+
+    /// Formats the remaining time of the decay into a human-readable string like `"2m 30s"`.
+    pub fn format_remaining(&self, format: &DurationFormat) -> String {
+        format.format(self.remaining())
+    }
+}
+
+impl<T: Component> fmt::Debug for DecayTimer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecayTimer")
+            .field("timer", &self.timer)
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+/// Event triggered when the decay process of the track `T` starts for an entity.
+///
+/// This event is dispatched when an entity with a `Decay` component begins the decay process.
+/// It provides the entity that is decaying and the duration for which the decay will last.
+#[derive(Event)]
+pub struct DecayStarted<T: Component = Decay> {
+    /// The entity that has started decaying.
+    pub entity: Entity,
+    /// The duration for which the entity will decay.
+    pub duration: Duration,
+    /// Whether an existing decay was resumed (e.g. unpaused, thawed or restored) rather than
+    /// started anew.
+    pub resumed: bool,
+    /// The `DecayTag` of the entity, if any.
+    pub tag: Option<DecayTag>,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayStarted<T> {
+    /// Creates a new `DecayStarted` event for the given entity and duration.
+    pub fn new(entity: Entity, duration: Duration) -> Self {
+        Self {
+            entity,
+            duration,
+            resumed: false,
+            tag: None,
+            track: PhantomData,
+        }
+    }
+
+    /// Sets the `DecayTag` of the entity carried by the event.
+    pub fn with_tag(mut self, tag: Option<DecayTag>) -> Self {
+        self.tag = tag;
+        self
+    }
+
+    /// Creates a new `DecayStarted` event for the given entity resuming its decay with the
+    /// given remaining duration.
+    pub fn resumed(entity: Entity, duration: Duration) -> Self {
+        Self {
+            resumed: true,
+            ..Self::new(entity, duration)
+        }
+    }
+}
+
+/// Reason for which the decay process of an entity was paused.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum DecayPauseReason {
+    /// The marker component of the track was removed from the entity.
+    ComponentRemoved,
+    /// The `DecayFrozen` component was added to the entity.
+    Frozen,
+    /// The group entity of the `DecayGroup` the entity belongs to was frozen.
+    GroupPaused,
+    /// The virtual clock was paused, or the app was suspended by `DecayFocusSuspend`, pausing the
+    /// decay of every entity.
+    GlobalPause,
+}
+
+/// Event triggered once per frame with every decay process of the track `T` that started
+/// during the frame.
+///
+/// Only triggered when the plugin is built with [`DecayPlugin::with_batched_started`], in
+/// which case it replaces the individual `DecayStarted` events. The event contains the
+/// entities that have started decaying along with the duration for which they will decay.
+#[derive(Event, Deref, DerefMut)]
+pub struct DecayStartedBatch<T: Component = Decay>(
+    #[deref] pub Vec<(Entity, Duration)>,
+    PhantomData<T>,
+);
+
+impl<T: Component> DecayStartedBatch<T> {
+    /// Creates a new `DecayStartedBatch` event for the given entities and durations.
+    pub fn new(started: Vec<(Entity, Duration)>) -> Self {
+        Self(started, PhantomData)
+    }
+}
+
+/// Buffer of the decay starts of the track `T` waiting to be triggered as a
+/// `DecayStartedBatch` at the end of the frame.
+#[derive(Resource)]
+pub struct DecayStartedBuffer<T: Component = Decay>(Vec<(Entity, Duration)>, PhantomData<T>);
+
+impl<T: Component> Default for DecayStartedBuffer<T> {
+    fn default() -> Self {
+        Self(Vec::new(), PhantomData)
+    }
+}
+
+/// Event triggered when the decay process of the track `T` is paused for an entity.
+///
+/// This event is fired when an entity with an active decay timer has its decay paused.
+/// It includes the entity, the remaining duration and the elapsed fraction of the decay at the
+/// time of pausing, as well as the reason for the pause.
+#[derive(Event)]
+pub struct DecayPaused<T: Component = Decay> {
+    /// The entity that has paused its decay process.
+    pub entity: Entity,
+    /// The remaining duration of decay when the process was paused.
+    pub remaining_duration: Duration,
+    /// The fraction of the decay that had elapsed when the process was paused, from 0 to 1.
+    pub fraction_elapsed: f32,
+    /// The reason for which the decay process was paused.
+    pub reason: DecayPauseReason,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayPaused<T> {
+    /// Creates a new `DecayPaused` event for the given entity, remaining duration, elapsed
+    /// fraction and reason.
+    pub fn new(
+        entity: Entity,
+        remaining_duration: Duration,
+        fraction_elapsed: f32,
+        reason: DecayPauseReason,
+    ) -> Self {
+        Self {
+            entity,
+            remaining_duration,
+            fraction_elapsed,
+            reason,
+            track: PhantomData,
+        }
+    }
+}
+
+/// Entities whose decay of the track `T` has completed during the current frame.
+///
+/// Guarantees that a decay cycle completes exactly once: an entity completing again in the
+/// same frame (e.g. when the completion systems run twice, or when commands race with the
+/// completion) is ignored until the next frame.
+#[derive(Resource)]
+pub struct DecayCompletionGuard<T: Component = Decay>(EntityHashSet, PhantomData<T>);
+
+impl<T: Component> Default for DecayCompletionGuard<T> {
+    fn default() -> Self {
+        Self(EntityHashSet::default(), PhantomData)
+    }
+}
+
+impl<T: Component> DecayCompletionGuard<T> {
+    /// Checks if the decay of the entity has completed during the current frame.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.0.contains(&entity)
+    }
+}
+
+/// Event triggered when the decay process of the track `T` is completed for entities bearing
+/// the marker component `M`.
+///
+/// Only triggered for the markers registered with [`DecayPlugin::with_completed_of`] or
+/// [`DecayAppExt::add_decay_completion_handler`]. It lets an observer handle the completions of
+/// one kind of entity (e.g. corpses) without iterating over every completion. The event
+/// contains the entities that have completed their decay.
+#[derive(Event, Deref, DerefMut)]
+pub struct DecayCompletedOf<M: Component, T: Component = Decay>(
+    #[deref] pub Vec<Entity>,
+    PhantomData<(M, T)>,
+);
+
+impl<M: Component, T: Component> DecayCompletedOf<M, T> {
+    /// Creates a new `DecayCompletedOf` event for the given entities.
+    pub fn new(entities: Vec<Entity>) -> Self {
+        Self(entities, PhantomData)
+    }
+}
+
+/// Registers the `DecayCompletedOf<M, T>` event and the observer triggering it, unless they
+/// were already registered.
+pub(crate) fn register_completed_of<M: Component, T: Component>(app: &mut App) {
+    if app
+        .world()
+        .contains_resource::<CompletedOfRegistered<M, T>>()
+    {
+        return;
+    }
+
+    app.insert_resource(CompletedOfRegistered::<M, T>(PhantomData))
+        .add_event::<DecayCompletedOf<M, T>>()
+        .add_observer(handle_decay_completed_of::<M, T>);
+}
+
+/// Resource marking the `DecayCompletedOf<M, T>` event as registered.
+#[derive(Resource)]
+struct CompletedOfRegistered<M: Component, T: Component>(PhantomData<(M, T)>);
+
+/// Event triggered when a stack of `DecayStacks` is consumed and the decay is restarted.
+///
+/// This event is fired for every stack except the final one, which completes the decay.
+/// It includes the entity, the remaining stacks and the duration of the restarted decay.
+#[derive(Event)]
+pub struct DecayStackConsumed<T: Component = Decay> {
+    /// The entity that has consumed a stack.
+    pub entity: Entity,
+    /// The number of stacks left, including the one now decaying.
+    pub remaining_stacks: u32,
+    /// The duration for which the entity will decay until the next stack is consumed.
+    pub duration: Duration,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayStackConsumed<T> {
+    /// Creates a new `DecayStackConsumed` event for the given entity, stacks and duration.
+    pub fn new(entity: Entity, remaining_stacks: u32, duration: Duration) -> Self {
+        Self {
+            entity,
+            remaining_stacks,
+            duration,
+            track: PhantomData,
+        }
+    }
+}
+
+/// Event triggered when the decay process of the track `T` is completed for an entity.
+///
+/// This event is sent when an entity's decay timer has finished and the decay process is complete.
+/// The event contains the entities that have completed their decay, along with their `DecayTag`.
+#[derive(Event, Deref, DerefMut)]
+pub struct DecayCompleted<T: Component = Decay>(
+    #[deref] pub Vec<Entity>,
+    PhantomData<T>,
+    /// The `DecayTag` of each entity, if any, in the same order as the entities.
+    Vec<Option<DecayTag>>,
+);
+
+impl<T: Component> DecayCompleted<T> {
+    /// Creates a new `DecayCompleted` event for the given entities.
+    pub fn new(entities: Vec<Entity>) -> Self {
+        Self(entities, PhantomData, Vec::new())
+    }
+
+    /// Sets the `DecayTag` of each entity, in the same order as the entities.
+    pub fn with_tags(mut self, tags: Vec<Option<DecayTag>>) -> Self {
+        self.2 = tags;
+        self
+    }
+
+    /// Returns the `DecayTag` of the entity at the given index, if any.
+    pub fn tag(&self, index: usize) -> Option<DecayTag> {
+        self.2.get(index).copied().flatten()
+    }
+
+    /// Iterates over the completed entities along with their `DecayTag`, if any.
+    pub fn iter_tagged(&self) -> impl Iterator<Item = (Entity, Option<DecayTag>)> + '_ {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(index, &entity)| (entity, self.tag(index)))
+    }
+}
+
+/// Query data used to start or resume the decay process of the track `T` for an entity.
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct DecayStartData<T: Component> {
+    /// The decaying entity.
+    entity: Entity,
+    /// The duration range for the decay.
+    duration: &'static DecayDuration,
+    /// The timer of the decay, if it was already started.
+    timer: Option<&'static mut DecayTimer<T>>,
+    /// Whether the entity is frozen.
+    frozen: Has<DecayFrozen>,
+    /// Whether the decay of the entity is blocked.
+    never: Has<NeverDecay>,
+    /// Whether the decay of the entity waits for the decay of another entity.
+    after: Has<DecayAfter>,
+    /// The elapsed offset the decay starts with, if any.
+    offset: Option<&'static DecayStartOffset>,
+    /// The delay before the decay begins, if any.
+    delay: Option<&'static DecayDelay>,
+    /// Whether the delay before the decay begins is being counted down.
+    delaying: Has<DecayDelayTimer<T>>,
+    /// The tag carried by the decay events, if any.
+    tag: Option<&'static DecayTag>,
+    /// The wall-clock expiry the duration is derived from, if any.
+    at: Option<&'static DecayAt<T>>,
+}
+
+impl<T: Component> DecayStartDataItem<'_, T> {
+    /// Returns the duration range of the decay, derived from its `DecayAt` expiry, if any.
+    fn decay_duration(&self) -> DecayDuration {
+        match self.at {
+            // An expiry that has already passed still completes the decay, on the next tick.
+            Some(at) => DecayDuration::new(at.remaining().max(Duration::from_millis(1))),
+            None => *self.duration,
+        }
+    }
+}
+
+/// System that handles the initiation of decay for entities when the marker component `T`
+/// (`Decay` by default) is added.
+///
+/// Under a `DecayConcurrency` cap, new decays beyond the cap are queued instead of starting.
+pub fn handle_decay_start<T: Component>(
+    trigger: Trigger<OnAdd, T>,
+    mut commands: Commands,
+    mut rng: DecayRng,
+    mut started_buffer: Option<ResMut<DecayStartedBuffer<T>>>,
+    concurrency: Option<ResMut<DecayConcurrency<T>>>,
+    active: Query<(), (With<T>, With<DecayTimer<T>>)>,
+    mut query: Query<DecayStartData<T>>,
+) {
+    let Ok(data) = query.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    // Beyond the concurrency cap, new decays wait for a free slot.
+    let new =
+        data.timer.is_none() && !data.never && !data.after && !data.decay_duration().is_zero();
+    if let Some(mut concurrency) = concurrency.filter(|_| new) {
+        if !concurrency.admit(data.entity, active.iter().len()) {
+            return;
+        }
+    }
+
+    start_decay(&mut commands, &mut rng, started_buffer.as_deref_mut(), data);
+}
+
+/// System that handles the initiation of decay for entities when the `DecayDuration` component
+/// is added after the marker component `T`.
+///
+/// Only needed when `Decay` does not require `DecayDuration`, since the duration may then be
+/// provided later by the user (e.g. from a registry).
+#[cfg(not(feature = "require-duration"))]
+pub fn handle_decay_duration_added<T: Component>(
+    trigger: Trigger<OnAdd, DecayDuration>,
+    mut commands: Commands,
+    mut rng: DecayRng,
+    mut started_buffer: Option<ResMut<DecayStartedBuffer<T>>>,
+    mut query: Query<DecayStartData<T>, With<T>>,
+) {
+    let Ok(data) = query.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    start_decay(&mut commands, &mut rng, started_buffer.as_deref_mut(), data);
+}
+
+/// Starts or resumes the decay process of the track `T` for an entity.
+///
+/// If the entity is frozen, the timer is left paused until the `DecayFrozen` component is removed.
+fn start_decay<T: Component>(
+    commands: &mut Commands,
+    rng: &mut DecayRng,
+    started_buffer: Option<&mut DecayStartedBuffer<T>>,
+    data: DecayStartDataItem<T>,
+) {
+    let entity = data.entity;
+    let duration = data.decay_duration();
+
+    // If the decay of the entity is blocked, it never starts.
+    // If the decay of the entity waits for another entity, it starts once released.
+    if data.never || data.after {
+        return;
+    }
+
+    // If the decay duration is zero, remove the marker and `DecayTimer` components immediately.
+    if duration.is_zero() {
+        commands
+            .entity(entity)
+            .remove::<T>()
+            .remove::<DecayTimer<T>>();
+        return;
+    }
+
+    // If the delay is being counted down, it resumes on its own.
+    if data.delaying {
+        return;
+    }
+
+    // If the decay has a delay, count it down before creating the timer.
+    if let (None, Some(delay)) = (&data.timer, data.delay) {
+        commands
+            .entity(entity)
+            .insert(DecayDelayTimer::<T>::new(**delay));
+        return;
+    }
+
+    // If the entity is frozen, only make sure a timer exists; it resumes once thawed.
+    if data.frozen {
+        if data.timer.is_none() {
+            let mut timer = new_timer::<T>(commands, rng, entity, &duration, data.offset);
+            timer.pause();
+            commands.entity(entity).insert(timer);
+        }
+        return;
+    }
+
+    // If a timer already exists, unpause it.
+    if let Some(mut timer) = data.timer {
+        timer.unpause();
+
+        // Trigger the `DecayStarted` event with the remaining duration.
+        let remaining = timer.remaining();
+        trigger_started(commands, started_buffer, entity, remaining, true, data.tag);
+        return;
+    }
+
+    // If no timer exists, create a new timer with a duration and start the decay process.
+    let timer = new_timer::<T>(commands, rng, entity, &duration, data.offset);
+    let remaining = timer.remaining();
+    commands.entity(entity).insert(timer);
+
+    // Trigger the `DecayStarted` event with the remaining duration.
+    trigger_started(commands, started_buffer, entity, remaining, false, data.tag);
+}
+
+/// Creates a new timer for the decay of the track `T`, with a duration rolled from the
+/// `DecayDuration` and the elapsed offset applied, if any.
+fn new_timer<T: Component>(
+    commands: &mut Commands,
+    rng: &mut DecayRng,
+    entity: Entity,
+    decay_duration: &DecayDuration,
+    offset: Option<&DecayStartOffset>,
+) -> DecayTimer<T> {
+    let mut timer = DecayTimer::<T>::new(rng.roll(entity, decay_duration));
+
+    // The offset only applies to the start of the decay, so it is consumed.
+    if let Some(offset) = offset {
+        let elapsed = offset.elapsed(timer.duration());
+        timer.set_elapsed(elapsed);
+        commands.entity(entity).remove::<DecayStartOffset>();
+    }
+
+    timer
+}
+
+/// Triggers the `DecayStarted` event for an entity, or buffers it for the `DecayStartedBatch`
+/// event when starts are batched.
+fn trigger_started<T: Component>(
+    commands: &mut Commands,
+    started_buffer: Option<&mut DecayStartedBuffer<T>>,
+    entity: Entity,
+    duration: Duration,
+    resumed: bool,
+    tag: Option<&DecayTag>,
+) {
+    let tag = tag.copied();
+    match started_buffer {
+        Some(started_buffer) => started_buffer.0.push((entity, duration)),
+        None if resumed => {
+            commands.trigger(DecayStarted::<T>::resumed(entity, duration).with_tag(tag))
+        }
+        None => commands.trigger(DecayStarted::<T>::new(entity, duration).with_tag(tag)),
+    }
+}
+
+/// System that triggers the `DecayStartedBatch` event with the decay starts of the frame.
+fn flush_started_batch<T: Component>(
+    mut commands: Commands,
+    mut started_buffer: ResMut<DecayStartedBuffer<T>>,
+) {
+    if !started_buffer.0.is_empty() {
+        let started = std::mem::take(&mut started_buffer.0);
+        commands.trigger(DecayStartedBatch::<T>::new(started));
+    }
+}
+
+/// System that handles pausing decay for entities when the marker component `T`
+/// (`Decay` by default) is removed.
+pub fn handle_decay_pause<T: Component>(
+    trigger: Trigger<OnRemove, T>,
+    mut commands: Commands,
+    guard: Res<DecayCompletionGuard<T>>,
+    mut query: Query<(Entity, &mut DecayTimer<T>)>,
+) {
+    if let Ok((entity, mut timer)) = query.get_mut(trigger.entity()) {
+        // If the timer is already paused (e.g. frozen), there is nothing left to pause.
+        // If the decay has completed, the marker is removed because of the completion.
+        if timer.paused() || guard.contains(entity) {
+            return;
+        }
+
+        // Pause the decay timer for the entity.
+        timer.pause();
+
+        // Send a `DecayPaused` event, including the remaining duration.
+        commands.trigger(DecayPaused::<T>::new(
+            entity,
+            timer.remaining(),
+            timer.fraction(),
+            DecayPauseReason::ComponentRemoved,
+        ));
+    }
+}
+
+/// System that handles freezing the decay of the track `T` when the `DecayFrozen` component
+/// is added.
+pub fn handle_decay_freeze<T: Component>(
+    trigger: Trigger<OnAdd, DecayFrozen>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut DecayTimer<T>, Option<&DecayPauseSources>), With<T>>,
+) {
+    if let Ok((entity, mut timer, sources)) = query.get_mut(trigger.entity()) {
+        // Pause the decay timer for the entity.
+        timer.pause();
+
+        // Send a `DecayPaused` event, including the remaining duration.
+        commands.trigger(DecayPaused::<T>::new(
+            entity,
+            timer.remaining(),
+            timer.fraction(),
+            pause::freeze_reason(sources),
+        ));
+    }
+}
+
+/// System that handles resuming the decay of the track `T` when the `DecayFrozen` component
+/// is removed.
+pub fn handle_decay_thaw<T: Component>(
+    trigger: Trigger<OnRemove, DecayFrozen>,
+    mut commands: Commands,
+    mut started_buffer: Option<ResMut<DecayStartedBuffer<T>>>,
+    mut query: Query<
+        (Entity, &mut DecayTimer<T>, Option<&DecayTag>),
+        (With<T>, Without<NeverDecay>),
+    >,
+) {
+    if let Ok((entity, mut timer, tag)) = query.get_mut(trigger.entity()) {
+        // Unpause the decay timer for the entity.
+        timer.unpause();
+
+        // Trigger the `DecayStarted` event with the remaining duration.
+        trigger_started(
+            &mut commands,
+            started_buffer.as_deref_mut(),
+            entity,
+            timer.remaining(),
+            true,
+            tag,
+        );
+    }
+}
+
+/// System that counts down the delays of entities before their decay begins, starting the
+/// decay once the delay has elapsed.
+pub fn tick_decay_delays<T: Component>(
+    time: Res<Time>,
+    suspend: Option<Res<DecayFocusSuspend>>,
+    mut commands: Commands,
+    mut rng: DecayRng,
+    mut started_buffer: Option<ResMut<DecayStartedBuffer<T>>>,
+    mut query: Query<
+        (
+            Entity,
+            &mut DecayDelayTimer<T>,
+            &DecayDuration,
+            Option<&DecayStartOffset>,
+            Option<&DecayTag>,
+        ),
+        (
+            With<T>,
+            Without<DecayFrozen>,
+            Without<NeverDecay>,
+            Without<DecayDisabled<T>>,
+        ),
+    >,
+) {
+    let Some(delta) = focus::decay_delta(&time, suspend.as_deref()) else {
+        return;
+    };
+
+    for (entity, mut delay_timer, decay_duration, offset, tag) in query.iter_mut() {
+        // Progress the delay timer based on the time elapsed since the last frame.
+        delay_timer.tick(delta);
+
+        if !delay_timer.finished() {
+            continue;
+        }
+
+        // The delay is over, so it is consumed and the decay timer is created.
+        let timer = new_timer::<T>(&mut commands, &mut rng, entity, decay_duration, offset);
+        let remaining = timer.remaining();
+        commands
+            .entity(entity)
+            .remove::<(DecayDelay, DecayDelayTimer<T>)>()
+            .insert(timer);
+
+        // Trigger the `DecayStarted` event with the remaining duration.
+        trigger_started(
+            &mut commands,
+            started_buffer.as_deref_mut(),
+            entity,
+            remaining,
+            false,
+            tag,
+        );
+    }
+}
+
+/// System that processes decaying entities by ticking their timers.
+///
+/// Registered by the `DecayPlugin` in `DecayingSet::Tick`; it may instead be placed in a custom
+/// schedule, before [`complete_decay`].
+///
+/// Frozen entities are filtered out by archetype, so paused inventories and depots cost nothing
+/// per frame. Entities with a `DecayLod` are likewise only visited once per interval, waiting
+/// with a `DecayLodWaiting` marker in between.
+#[allow(clippy::too_many_arguments)]
+pub fn decaying<T: Component>(
+    mut commands: Commands,
+    time: Res<Time>,
+    suspend: Option<Res<DecayFocusSuspend>>,
+    mut schedule: ResMut<DecayLodSchedule<T>>,
+    metrics: Option<ResMut<DecayTrackMetrics<T>>>,
+    entities: &Entities,
+    mut overshoot: Option<ResMut<DecayOvershootHistogram<T>>>,
+    mut query: Query<
+        (
+            Entity,
+            &mut DecayTimer<T>,
+            rate::DecaySpeed,
+            Option<&DecayLod>,
+        ),
+        (DecayingFilter<T>, Without<DecayLodWaiting<T>>),
+    >,
+    mut waiting: Query<
+        (
+            &mut DecayTimer<T>,
+            &DecayLodWaiting<T>,
+            rate::DecaySpeed,
+            &DecayLod,
+        ),
+        DecayingFilter<T>,
+    >,
+) {
+    // While the app is unfocused, the decays may be suspended.
+    let Some(delta) = focus::decay_delta(&time, suspend.as_deref()) else {
+        return;
+    };
+
+    // Only measure the time spent ticking when the metrics are enabled.
+    let start = (metrics.is_some() || cfg!(feature = "metrics")).then(Instant::now);
+    let mut stopwatch = metrics
+        .is_some()
+        .then(diagnostic::ArchetypeStopwatch::default);
+
+    let clock = schedule.advance(delta);
+
+    // Tick the entities whose level of detail is due with the time elapsed since their last
+    // tick, scheduling their next tick until they finish.
+    for entity in schedule.take_due() {
+        let Ok((mut timer, waiting, speed, lod)) = waiting.get_mut(entity) else {
+            continue;
+        };
+        if let Some(stopwatch) = stopwatch.as_mut() {
+            stopwatch.enter(entities, entity);
+        }
+
+        // Entries of markers replaced since were scheduled for a tick that is not due.
+        if !waiting.is_due(clock) {
+            continue;
+        }
+
+        let multiplier = speed.multiplier_at(timer.fraction());
+        timer.pending += waiting.elapsed(clock).mul_f32(multiplier);
+
+        // Paused timers return to the per-frame ticking, holding the elapsed time.
+        if timer.paused() {
+            commands.entity(entity).remove::<DecayLodWaiting<T>>();
+            continue;
+        }
+
+        tick(
+            entity,
+            &mut timer,
+            multiplier,
+            &time,
+            overshoot.as_deref_mut(),
+        );
 
-        // If the timer has completed its countdown...
         if timer.finished() {
-            // Remove the `Decay` and `DecayTimer` components from the entity.
-            commands
-                .entity(entity)
-                .remove::<Decay>()
-                .remove::<DecayTimer>();
+            commands.entity(entity).remove::<DecayLodWaiting<T>>();
+        } else {
+            let next = schedule.wait(entity, lod.interval, timer.remaining(), multiplier);
+            commands.entity(entity).try_insert(next);
+        }
+    }
+
+    for (entity, mut timer, speed, lod) in query.iter_mut() {
+        if let Some(stopwatch) = stopwatch.as_mut() {
+            stopwatch.enter(entities, entity);
+        }
 
-            // Collect the entity for triggering...
-            decayed_entities.push(entity);
+        // Paused timers do not progress, nor accumulate elapsed time.
+        if timer.paused() {
+            continue;
         }
+
+        // Accumulate the time elapsed since the last frame, scaled by the decay speed.
+        let multiplier = speed.multiplier_at(timer.fraction());
+        timer.pending += delta.mul_f32(multiplier);
+        tick(
+            entity,
+            &mut timer,
+            multiplier,
+            &time,
+            overshoot.as_deref_mut(),
+        );
+
+        // With a level of detail, wait out of the per-frame ticking until the next tick.
+        if let Some(lod) = lod.filter(|_| !timer.finished()) {
+            let next = schedule.wait(entity, lod.interval, timer.remaining(), multiplier);
+            commands.entity(entity).try_insert(next);
+        }
+    }
+
+    let Some(tick_time) = start.map(|start| start.elapsed()) else {
+        return;
+    };
+
+    if let (Some(mut metrics), Some(stopwatch)) = (metrics, stopwatch) {
+        metrics.record_tick(tick_time, stopwatch.finish());
     }
 
+    #[cfg(feature = "metrics")]
+    telemetry::record_tick_time::<T>(tick_time);
+}
+
+/// Query filter for the decays of the track `T` ticked by [`decaying`].
+type DecayingFilter<T> = (
+    With<T>,
+    Without<DecayFrozen>,
+    Without<NeverDecay>,
+    Without<DecayLongLaneMember<T>>,
+    Without<DecayDisabled<T>>,
+);
+
+/// Applies the elapsed time accumulated by a decay timer, recording when it finished in the
+/// overshoot histogram, if any.
+fn tick<T: Component>(
+    entity: Entity,
+    timer: &mut DecayTimer<T>,
+    multiplier: f32,
+    time: &Time,
+    overshoot: Option<&mut DecayOvershootHistogram<T>>,
+) {
+    let pending = std::mem::take(&mut timer.pending);
+    let remaining = timer.timer.remaining();
+    timer.tick(pending);
+
+    // Estimate when the timer actually finished, from the part of the tick beyond it.
+    if let Some(overshoot) = overshoot.filter(|_| timer.just_finished()) {
+        let excess = pending.saturating_sub(remaining);
+        let excess = if multiplier > 0.0 {
+            excess.div_f32(multiplier)
+        } else {
+            Duration::ZERO
+        };
+        overshoot.record_deadline(entity, time.elapsed().saturating_sub(excess));
+    }
+}
+
+/// System that completes the decay of entities whose timer has finished.
+///
+/// With a background scan, only the entities whose deadline has passed are checked. With a
+/// completion budget, only the most urgent finished decays complete, the others being deferred.
+/// Members of a group barrier only complete once their whole group has finished.
+///
+/// When every decaying entity is checked, the completions are recorded in parallel, spreading
+/// the removals of mass completions across threads. The completions and consumed stacks are
+/// then ordered by entity, so their order does not depend on the number of threads.
+#[allow(clippy::too_many_arguments)]
+pub fn complete_decay<T: Component>(
+    time: Res<Time>,
+    par_commands: ParallelCommands,
+    mut completed: Local<Parallel<Vec<Completion<T>>>>,
+    mut guard: ResMut<DecayCompletionGuard<T>>,
+    barriers: Res<DecayBarriers<T>>,
+    mut scan: Option<ResMut<scan::DecayScan<T>>>,
+    budget: Option<Res<DecayCompletionBudget<T>>>,
+    priorities: Query<&DecayPriority>,
+    tags: Query<&DecayTag>,
+    mut query: Query<
+        (
+            Entity,
+            &mut DecayTimer<T>,
+            Option<&DecayDuration>,
+            Option<&mut DecayStacks>,
+            Option<&DecayCharges>,
+        ),
+        (With<T>, Without<NeverDecay>, Without<DecayDisabled<T>>),
+    >,
+) {
+    let mut decayed_entities = vec![];
+
+    // With a background scan, only check the entities whose deadline has passed.
+    let mut ready = scan.as_mut().map(|scan| scan.take_ready(time.elapsed()));
+
+    // Members of a group barrier wait for their group, and are checked again by the next scan.
+    if let (Some(ready), Some(scan)) = (ready.as_mut(), scan.as_mut()) {
+        let (held, rest) = ready
+            .drain(..)
+            .partition(|entity| barriers.held.contains(entity));
+        *ready = rest;
+        scan.defer(held);
+    }
+
+    // Under a completion budget, only check the most urgent finished decays.
+    let budgeted = budget.map(|budget| {
+        let mut finished: Vec<_> = match &ready {
+            Some(ready) => query
+                .iter_many(ready)
+                .filter_map(|(entity, timer, .., charges)| {
+                    is_finished(timer, charges).then_some(entity)
+                })
+                .collect(),
+            None => query
+                .iter()
+                .filter(|(entity, ..)| !barriers.held.contains(entity))
+                .filter_map(|(entity, timer, .., charges)| {
+                    is_finished(timer, charges).then_some(entity)
+                })
+                .collect(),
+        };
+
+        finished
+            .sort_by_key(|&entity| Reverse(priorities.get(entity).copied().unwrap_or_default()));
+
+        // The deferred decays stay finished, or are checked again by the next scan.
+        let deferred = finished.split_off(budget.max_per_frame.min(finished.len()));
+        if let Some(scan) = scan.as_mut() {
+            scan.defer(deferred);
+        }
+
+        finished
+    });
+
+    let mut completions = vec![];
+
+    match budgeted.or(ready) {
+        Some(entities) => par_commands.command_scope(|mut commands| {
+            let mut items = query.iter_many_mut(entities);
+            while let Some(item) = items.fetch_next() {
+                completions.extend(try_complete(&mut commands, &guard, item));
+            }
+        }),
+        None => {
+            let guard = &*guard;
+            query.par_iter_mut().for_each(|item| {
+                if barriers.held.contains(&item.0) {
+                    return;
+                }
+
+                par_commands.command_scope(|mut commands| {
+                    if let Some(completion) = try_complete(&mut commands, guard, item) {
+                        completed.borrow_local_mut().push(completion);
+                    }
+                });
+            });
+            completed.drain_into(&mut completions);
+
+            // Threads record in any order, so restore a deterministic one.
+            completions.sort_unstable_by_key(Completion::entity);
+        }
+    }
+
+    par_commands.command_scope(|mut commands| {
+        for completion in completions {
+            match completion {
+                Completion::Completed(entity) => decayed_entities.push(entity),
+                Completion::StackConsumed(event) => commands.trigger(event),
+            }
+        }
+    });
+
+    // If an entity was checked twice, only complete it once.
+    decayed_entities.retain(|&entity| guard.0.insert(entity));
+
     // If any entities have completed decaying, trigger the DecayCompleted event.
     if !decayed_entities.is_empty() {
-        commands.trigger(DecayCompleted(decayed_entities));
+        par_commands.command_scope(|mut commands| {
+            let tags = decayed_entities
+                .iter()
+                .map(|&entity| tags.get(entity).ok().copied())
+                .collect();
+            commands.trigger(DecayCompleted::<T>::new(decayed_entities).with_tags(tags));
+        });
+    }
+}
+
+/// Checks if the decay of an entity has finished, either because its timer has finished or
+/// because its charges have run out.
+pub(crate) fn is_finished<T: Component>(
+    timer: &DecayTimer<T>,
+    charges: Option<&DecayCharges>,
+) -> bool {
+    timer.finished() || charges.is_some_and(|charges| charges.0 == 0)
+}
+
+/// Outcome of the completion of a finished decay.
+enum Completion<T: Component> {
+    /// The decay completed, and its components are being removed.
+    Completed(Entity),
+    /// A stack was consumed, restarting the timer.
+    StackConsumed(DecayStackConsumed<T>),
+}
+
+impl<T: Component> Completion<T> {
+    /// Returns the entity of the decay.
+    fn entity(&self) -> Entity {
+        match self {
+            Completion::Completed(entity) => *entity,
+            Completion::StackConsumed(event) => event.entity,
+        }
+    }
+}
+
+/// Completes the decay of an entity if its timer has finished, returning the outcome.
+fn try_complete<T: Component>(
+    commands: &mut Commands,
+    guard: &DecayCompletionGuard<T>,
+    (entity, mut timer, decay_duration, stacks, charges): (
+        Entity,
+        Mut<DecayTimer<T>>,
+        Option<&DecayDuration>,
+        Option<Mut<DecayStacks>>,
+        Option<&DecayCharges>,
+    ),
+) -> Option<Completion<T>> {
+    // If the charges have run out, the decay completes regardless of its timer or stacks.
+    let charges_exhausted = charges.is_some_and(|charges| charges.0 == 0);
+
+    // If the timer has completed its countdown...
+    if timer.finished() || charges_exhausted {
+        // If more than one stack is left, consume a stack and restart the timer instead.
+        if let Some(mut stacks) = stacks.filter(|stacks| stacks.0 > 1 && !charges_exhausted) {
+            stacks.0 -= 1;
+
+            // Re-roll the duration, or reuse the previous one if it is not available.
+            let duration = decay_duration.map_or(timer.duration(), Duration::from);
+            timer.set_duration(duration);
+            timer.reset();
+
+            return Some(Completion::StackConsumed(DecayStackConsumed::new(
+                entity, stacks.0, duration,
+            )));
+        }
+
+        // If the entity has already completed this frame, do not complete it twice.
+        if guard.contains(entity) {
+            return None;
+        }
+
+        // Remove the marker and `DecayTimer` components from the entity at once.
+        commands.entity(entity).remove::<(T, DecayTimer<T>)>();
+
+        // Collect the entity for triggering...
+        return Some(Completion::Completed(entity));
+    }
+
+    None
+}
+
+/// System that clears the entities completed during the previous frame.
+///
+/// Registered by the `DecayPlugin` in `First`; when scheduling the decay systems manually, it
+/// must run once per frame before them.
+pub fn clear_completion_guard<T: Component>(mut guard: ResMut<DecayCompletionGuard<T>>) {
+    guard.0.clear();
+}
+
+/// System that triggers `DecayCompletedOf<M, T>` with the entities bearing the marker
+/// component `M` among those that have completed decaying.
+fn handle_decay_completed_of<M: Component, T: Component>(
+    trigger: Trigger<DecayCompleted<T>>,
+    mut commands: Commands,
+    query: Query<(), With<M>>,
+) {
+    let entities: Vec<_> = trigger
+        .iter()
+        .copied()
+        .filter(|entity| query.contains(*entity))
+        .collect();
+
+    if !entities.is_empty() {
+        commands.trigger(DecayCompletedOf::<M, T>::new(entities));
+    }
+}
+
+/// System that executes the `DecayAction` and `DecayContents` of entities that have completed
+/// decaying.
+fn handle_decay_action<T: Component>(
+    trigger: Trigger<DecayCompleted<T>>,
+    mut commands: Commands,
+    mut despawner: Option<ResMut<DecayDespawner>>,
+    query: Query<(
+        Option<&DecayAction>,
+        Option<&DecayContents>,
+        Option<&Children>,
+        Option<&GlobalTransform>,
+    )>,
+) {
+    for entity in trigger.iter() {
+        let Ok((action, contents, children, transform)) = query.get(*entity) else {
+            continue;
+        };
+
+        // Handle the contents first, so they can be dropped before the container is despawned.
+        if let (Some(&contents), Some(children)) = (contents, children) {
+            let position = transform.map(GlobalTransform::translation);
+            action::cascade_contents(&mut commands, contents, children, position);
+        }
+
+        let Some(action) = action else {
+            continue;
+        };
+
+        match action {
+            DecayAction::None => {}
+            // Despawn the entity and all of its children, unless batched by the despawner.
+            DecayAction::Despawn => match despawner.as_mut() {
+                Some(despawner) => despawner.push(*entity),
+                None => commands.entity(*entity).despawn_recursive(),
+            },
+            // Let the provided function transform the entity.
+            DecayAction::Transform(transform) => transform(&mut commands.entity(*entity)),
+        }
     }
 }
@@ -0,0 +1,286 @@
+use crate::{Decay, DecayDisabled, DecayDuration, DecayFrozen, DecaySpeed, DecayTimer};
+use bevy::prelude::*;
+use std::{cmp::Reverse, collections::BinaryHeap, marker::PhantomData, time::Duration};
+
+/// Level of detail reducing how often the decay of an entity is ticked.
+///
+/// Instead of being visited every frame, the entity waits out of the per-frame ticking with a
+/// `DecayLodWaiting` marker, and its decay timers are ticked with the time elapsed meanwhile
+/// once the `interval` has passed, or once the decay would finish. The decay lasts just as long
+/// and completes on the same frame, which cuts the cost of distant or unimportant entities
+/// without changing outcomes.
+///
+/// Between ticks, the timers lag behind by up to one interval, and the decay speed is sampled
+/// once per tick.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct DecayLod {
+    /// The time between two ticks of the decay timers.
+    pub interval: Duration,
+}
+
+impl DecayLod {
+    /// Creates a new `DecayLod` ticking the decay every `interval`.
+    pub fn new(interval: Duration) -> Self {
+        Self { interval }
+    }
+}
+
+/// Component marking the entities whose decay of the track `T` waits for the next tick of
+/// their `DecayLod`, out of the per-frame ticking.
+///
+/// It is managed by the plugin, and only public so the waiting entities can be queried or
+/// excluded.
+#[derive(Component, Debug)]
+#[component(storage = "SparseSet")]
+pub struct DecayLodWaiting<T: Component = Decay> {
+    /// The clock of the schedule at the last tick of the entity.
+    since: Duration,
+    /// The clock of the schedule at the next tick of the entity.
+    due: Duration,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+/// Resource scheduling the next ticks of the entities waiting for their `DecayLod` on the track
+/// `T`.
+///
+/// It is managed by the plugin, and only public so [`decaying`](crate::decaying) can be
+/// scheduled manually.
+#[derive(Resource)]
+pub struct DecayLodSchedule<T: Component = Decay> {
+    /// The decay time elapsed since the schedule was created.
+    clock: Duration,
+    /// The upcoming ticks, soonest first.
+    ticks: BinaryHeap<Reverse<(Duration, Entity)>>,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> Default for DecayLodSchedule<T> {
+    fn default() -> Self {
+        Self {
+            clock: Duration::ZERO,
+            ticks: BinaryHeap::new(),
+            track: PhantomData,
+        }
+    }
+}
+
+impl<T: Component> DecayLodSchedule<T> {
+    /// Advances the clock by the decay time of the frame, returning the new clock.
+    pub(crate) fn advance(&mut self, delta: Duration) -> Duration {
+        self.clock += delta;
+        self.clock
+    }
+
+    /// Takes the entities whose tick is due.
+    pub(crate) fn take_due(&mut self) -> Vec<Entity> {
+        let mut due = vec![];
+        while let Some(&Reverse((tick, entity))) = self.ticks.peek() {
+            if tick > self.clock {
+                break;
+            }
+            self.ticks.pop();
+            due.push(entity);
+        }
+        due
+    }
+
+    /// Schedules the next tick of an entity, once the interval has passed or its decay would
+    /// finish at the given speed multiplier, returning its marker.
+    pub(crate) fn wait(
+        &mut self,
+        entity: Entity,
+        interval: Duration,
+        remaining: Duration,
+        multiplier: f32,
+    ) -> DecayLodWaiting<T> {
+        let until = if multiplier > 0.0 {
+            interval.min(remaining.div_f32(multiplier))
+        } else {
+            interval
+        };
+
+        let due = self.clock + until;
+        self.ticks.push(Reverse((due, entity)));
+        DecayLodWaiting {
+            since: self.clock,
+            due,
+            track: PhantomData,
+        }
+    }
+
+    /// Returns the decay time elapsed since the schedule was created.
+    pub(crate) fn clock(&self) -> Duration {
+        self.clock
+    }
+}
+
+impl<T: Component> DecayLodWaiting<T> {
+    /// Checks if the tick of the entity is due at the given clock, rather than the marker
+    /// having been replaced since the tick was scheduled.
+    pub(crate) fn is_due(&self, clock: Duration) -> bool {
+        self.due <= clock
+    }
+
+    /// Returns the decay time elapsed since the last tick of the entity at the given clock.
+    pub(crate) fn elapsed(&self, clock: Duration) -> Duration {
+        clock.saturating_sub(self.since)
+    }
+}
+
+/// Marker component for entities around which decay is ticked at full frequency, such as
+/// cameras or players.
+///
+/// Used by the `DecayLodPolicy` to decide which entities are far away.
+#[derive(Component, Default, Debug)]
+pub struct DecayLodAnchor;
+
+/// Policy assigning a `DecayLod` to decaying entities far from every `DecayLodAnchor`.
+///
+/// When this resource is present, every decaying entity with a `GlobalTransform` further
+/// than `distance` from the nearest anchor gets a `DecayLod` with the given `interval`, and
+/// nearer entities have it removed. The policy takes over the `DecayLod` of those entities.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct DecayLodPolicy {
+    /// The distance from the nearest anchor beyond which the level of detail applies.
+    pub distance: f32,
+    /// The interval of the level of detail applied to far away entities.
+    pub interval: Duration,
+}
+
+/// System that assigns the level of detail of decaying entities based on the `DecayLodPolicy`.
+pub(crate) fn apply_decay_lod_policy(
+    mut commands: Commands,
+    policy: Res<DecayLodPolicy>,
+    anchors: Query<&GlobalTransform, With<DecayLodAnchor>>,
+    query: Query<(Entity, &GlobalTransform, Option<&DecayLod>), With<DecayDuration>>,
+) {
+    let distance_squared = policy.distance * policy.distance;
+
+    for (entity, transform, lod) in query.iter() {
+        let point = transform.translation();
+
+        // The entity is near if any anchor is within the distance.
+        let near = anchors
+            .iter()
+            .any(|anchor| anchor.translation().distance_squared(point) <= distance_squared);
+
+        match (near, lod) {
+            // Tick near entities at full frequency.
+            (true, Some(_)) => {
+                commands.entity(entity).remove::<DecayLod>();
+            }
+            // Tick far away entities at the interval of the policy.
+            (false, lod) if lod.is_none_or(|lod| lod.interval != policy.interval) => {
+                commands
+                    .entity(entity)
+                    .insert(DecayLod::new(policy.interval));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Query data of the entities waiting for their `DecayLod` on the track `T`.
+type WaitingData<T> = (
+    Entity,
+    &'static mut DecayTimer<T>,
+    &'static DecayLodWaiting<T>,
+    DecaySpeed,
+);
+
+/// Returns the entity to the per-frame ticking, holding the time elapsed since its last tick in
+/// its timer.
+fn stop_waiting<T: Component>(
+    entity: Entity,
+    commands: &mut Commands,
+    schedule: &DecayLodSchedule<T>,
+    query: &mut Query<WaitingData<T>>,
+) {
+    if let Ok((_, mut timer, waiting, speed)) = query.get_mut(entity) {
+        let elapsed = waiting.elapsed(schedule.clock);
+        timer.pending += elapsed.mul_f32(speed.multiplier_at(timer.fraction()));
+        commands.entity(entity).remove::<DecayLodWaiting<T>>();
+    }
+}
+
+/// System that returns waiting entities to the per-frame ticking when their decay of the track
+/// `T` is paused or completed.
+pub(crate) fn stop_waiting_on_pause<T: Component>(
+    trigger: Trigger<OnRemove, T>,
+    mut commands: Commands,
+    schedule: Res<DecayLodSchedule<T>>,
+    mut query: Query<WaitingData<T>>,
+) {
+    stop_waiting(trigger.entity(), &mut commands, &schedule, &mut query);
+}
+
+/// System that returns waiting entities to the per-frame ticking when they are frozen.
+pub(crate) fn stop_waiting_on_freeze<T: Component>(
+    trigger: Trigger<OnAdd, DecayFrozen>,
+    mut commands: Commands,
+    schedule: Res<DecayLodSchedule<T>>,
+    mut query: Query<WaitingData<T>>,
+) {
+    stop_waiting(trigger.entity(), &mut commands, &schedule, &mut query);
+}
+
+/// System that returns waiting entities to the per-frame ticking when their decay of the track
+/// `T` is skipped while disabled.
+pub(crate) fn stop_waiting_on_disable<T: Component>(
+    trigger: Trigger<OnAdd, DecayDisabled<T>>,
+    mut commands: Commands,
+    schedule: Res<DecayLodSchedule<T>>,
+    mut query: Query<WaitingData<T>>,
+) {
+    stop_waiting(trigger.entity(), &mut commands, &schedule, &mut query);
+}
+
+/// System that returns waiting entities to the per-frame ticking when they lose their
+/// `DecayLod`.
+pub(crate) fn stop_waiting_on_removal<T: Component>(
+    trigger: Trigger<OnRemove, DecayLod>,
+    mut commands: Commands,
+    schedule: Res<DecayLodSchedule<T>>,
+    mut query: Query<WaitingData<T>>,
+) {
+    stop_waiting(trigger.entity(), &mut commands, &schedule, &mut query);
+}
+
+/// Returns the given entities to the per-frame ticking at once if they wait for their
+/// `DecayLod` on the track `T`, holding the time elapsed since their last tick in their timers.
+///
+/// Used before fast-forwarding them, so the time they lag behind is applied first.
+pub(crate) fn stop_waiting_all<T: Component>(
+    world: &mut World,
+    entities: impl IntoIterator<Item = Entity>,
+) {
+    let Some(clock) = world
+        .get_resource::<DecayLodSchedule<T>>()
+        .map(DecayLodSchedule::clock)
+    else {
+        return;
+    };
+
+    let mut query = world.query::<WaitingData<T>>();
+    for entity in entities {
+        let Ok((_, mut timer, waiting, speed)) = query.get_mut(world, entity) else {
+            continue;
+        };
+
+        let elapsed = waiting.elapsed(clock);
+        timer.pending += elapsed.mul_f32(speed.multiplier_at(timer.fraction()));
+        world.entity_mut(entity).remove::<DecayLodWaiting<T>>();
+    }
+}
+
+/// Returns the time until the next entity waiting for its `DecayLod` on the track `T` is
+/// ticked, if any.
+///
+/// Used by simulations, so their steps do not skip past the ticks of waiting entities.
+pub(crate) fn next_tick<T: Component>(world: &World) -> Option<Duration> {
+    let schedule = world.get_resource::<DecayLodSchedule<T>>()?;
+    let Reverse((tick, _)) = schedule.ticks.peek()?;
+    Some(tick.saturating_sub(schedule.clock))
+}
@@ -0,0 +1,134 @@
+use crate::{Decay, DecayDelayTimer, DecayDuration, DecayTimer};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Starts the decay of the track `T` (`Decay` by default) once the component `C` is added to
+/// the entity, such as a corpse starting to rot once `Dead` is added.
+///
+/// When `C` appears (or if the entity already has it), this component is removed and the `T`
+/// marker is inserted, along with the duration if one was given. It is handled by the
+/// `DecayPlugin<T>` registered with [`DecayPlugin::start_on`].
+///
+/// [`DecayPlugin::start_on`]: crate::DecayPlugin::start_on
+#[derive(Component)]
+pub struct StartDecayOn<C: Component, T: Component = Decay> {
+    /// The marker component of the decay track.
+    marker: T,
+    /// The duration for the decay, if it replaces the one of the entity.
+    duration: Option<DecayDuration>,
+    /// The component starting the decay.
+    component: PhantomData<C>,
+}
+
+impl<C: Component, T: Component> StartDecayOn<C, T> {
+    /// Creates a new `StartDecayOn` inserting the given track marker.
+    pub fn new(marker: T) -> Self {
+        Self {
+            marker,
+            duration: None,
+            component: PhantomData,
+        }
+    }
+
+    /// Inserts the given duration along with the track marker.
+    pub fn with_duration(mut self, duration: DecayDuration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+}
+
+impl<C: Component, T: Component + Default> Default for StartDecayOn<C, T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+/// Cancels the decay of the track `T` (`Decay` by default) once the component `C` is added to
+/// the entity, such as an item becoming `Preserved` or `QuestLocked`.
+///
+/// Unlike a pause, the marker is removed along with the timer, so the elapsed time is lost and
+/// a later decay starts over. The rule stays on the entity and applies every time `C` is
+/// added (or when the rule is inserted while the entity has `C`). It is handled by the
+/// `DecayPlugin<T>` registered with [`DecayPlugin::cancel_on`].
+///
+/// [`DecayPlugin::cancel_on`]: crate::DecayPlugin::cancel_on
+#[derive(Component)]
+pub struct CancelDecayOn<C: Component, T: Component = Decay> {
+    /// The component cancelling the decay, and the marker component of the decay track.
+    marker: PhantomData<(C, T)>,
+}
+
+impl<C: Component, T: Component> Default for CancelDecayOn<C, T> {
+    fn default() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+/// System that starts the decay of entities whose component `C` was added.
+pub(crate) fn start_decay_on_add<C: Component, T: Component>(
+    trigger: Trigger<OnAdd, C>,
+    mut commands: Commands,
+    query: Query<(), With<StartDecayOn<C, T>>>,
+) {
+    if query.contains(trigger.entity()) {
+        commands.entity(trigger.entity()).queue(start::<C, T>);
+    }
+}
+
+/// System that starts the decay of entities given a `StartDecayOn<C>` while already having
+/// the component `C`.
+pub(crate) fn start_decay_on_insert<C: Component, T: Component>(
+    trigger: Trigger<OnAdd, StartDecayOn<C, T>>,
+    mut commands: Commands,
+    query: Query<(), With<C>>,
+) {
+    if query.contains(trigger.entity()) {
+        commands.entity(trigger.entity()).queue(start::<C, T>);
+    }
+}
+
+/// Replaces the `StartDecayOn<C>` of the entity with the track marker and its duration.
+fn start<C: Component, T: Component>(mut entity: EntityWorldMut) {
+    let Some(start) = entity.take::<StartDecayOn<C, T>>() else {
+        return;
+    };
+
+    match start.duration {
+        Some(duration) => entity.insert((duration, start.marker)),
+        None => entity.insert(start.marker),
+    };
+}
+
+/// System that cancels the decay of entities whose component `C` was added.
+pub(crate) fn cancel_decay_on_add<C: Component, T: Component>(
+    trigger: Trigger<OnAdd, C>,
+    mut commands: Commands,
+    query: Query<(), With<CancelDecayOn<C, T>>>,
+) {
+    if query.contains(trigger.entity()) {
+        commands.entity(trigger.entity()).queue(cancel::<T>);
+    }
+}
+
+/// System that cancels the decay of entities given a `CancelDecayOn<C>` while already having
+/// the component `C`.
+pub(crate) fn cancel_decay_on_insert<C: Component, T: Component>(
+    trigger: Trigger<OnAdd, CancelDecayOn<C, T>>,
+    mut commands: Commands,
+    query: Query<(), With<C>>,
+) {
+    if query.contains(trigger.entity()) {
+        commands.entity(trigger.entity()).queue(cancel::<T>);
+    }
+}
+
+/// Removes the track marker and the timers of the decay of the entity.
+///
+/// The timers are removed first, so removing the marker is not reported as a pause.
+pub(crate) fn cancel<T: Component>(mut entity: EntityWorldMut) {
+    entity
+        .remove::<(DecayTimer<T>, DecayDelayTimer<T>)>()
+        .remove::<T>();
+}
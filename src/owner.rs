@@ -0,0 +1,98 @@
+use crate::DecayEntityCommandsExt;
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Plugin pausing the decay of items while their owner bears the marker component `M`
+/// (`Offline` by default).
+///
+/// The decay of an item with a `DecayOwner` is frozen when its owner gains the marker, and
+/// resumes when the owner loses it, e.g. once the player logs back in. Use
+/// `DecayOwnerPlugin::default()` for the `Offline` marker and `DecayOwnerPlugin::<M>::new()`
+/// for any other marker.
+///
+/// Owned items are paused through the `"owner"` source of their `DecayPauseSources`, so other
+/// sources pausing them are left untouched.
+pub struct DecayOwnerPlugin<M: Component = Offline> {
+    /// The marker component pausing the decay of owned items.
+    marker: PhantomData<M>,
+}
+
+// `Default` is only implemented for the `Offline` marker, so `DecayOwnerPlugin::default()` can
+// be inferred without naming the marker.
+#[allow(clippy::new_without_default)]
+impl<M: Component> DecayOwnerPlugin<M> {
+    /// Creates a new `DecayOwnerPlugin` pausing the decay of owned items while their owner
+    /// bears the marker component `M`.
+    pub fn new() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl Default for DecayOwnerPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: Component> Plugin for DecayOwnerPlugin<M> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle_owner_away::<M>)
+            .add_observer(handle_owner_back::<M>)
+            .add_observer(handle_decay_owner_added::<M>);
+    }
+}
+
+/// The source pausing the decay of owned items.
+const OWNER_SOURCE: &str = "owner";
+
+/// Relation from a decaying item to the entity owning it, such as a player.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DecayOwner(pub Entity);
+
+/// Marker component for owners that are offline, pausing the decay of their items.
+#[derive(Component, Default, Debug)]
+pub struct Offline;
+
+/// System that pauses the decay of the items of an owner when it gains the marker `M`.
+fn handle_owner_away<M: Component>(
+    trigger: Trigger<OnAdd, M>,
+    mut commands: Commands,
+    items: Query<(Entity, &DecayOwner)>,
+) {
+    let owner = trigger.entity();
+    for (item, _) in items.iter().filter(|(_, item_owner)| item_owner.0 == owner) {
+        commands.entity(item).pause_decay(OWNER_SOURCE);
+    }
+}
+
+/// System that resumes the decay of the items of an owner when it loses the marker `M`.
+fn handle_owner_back<M: Component>(
+    trigger: Trigger<OnRemove, M>,
+    mut commands: Commands,
+    items: Query<(Entity, &DecayOwner)>,
+) {
+    let owner = trigger.entity();
+    for (item, _) in items.iter().filter(|(_, item_owner)| item_owner.0 == owner) {
+        commands.entity(item).resume_decay(OWNER_SOURCE);
+    }
+}
+
+/// System that pauses the decay of an item when it is given to an owner bearing the
+/// marker `M`.
+fn handle_decay_owner_added<M: Component>(
+    trigger: Trigger<OnAdd, DecayOwner>,
+    mut commands: Commands,
+    items: Query<&DecayOwner>,
+    owners: Query<(), With<M>>,
+) {
+    let item = trigger.entity();
+    let Ok(owner) = items.get(item) else {
+        return;
+    };
+
+    if owners.contains(owner.0) {
+        commands.entity(item).pause_decay(OWNER_SOURCE);
+    }
+}
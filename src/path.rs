@@ -0,0 +1,142 @@
+use crate::{pause, DecayModifiers, DecayPauseSources, DecayTimer};
+use bevy::{prelude::*, reflect::GetPath};
+use std::{error::Error, fmt, time::Duration};
+
+/// The pause source and modifier key of the changes made through reflection paths.
+const PATH_SOURCE: &str = "reflect";
+
+/// Reflected view of the decay of an entity, edited through reflection paths by tools that do
+/// not link against the concrete decay components, such as a dev console or an editor.
+///
+/// Paths are the names of the fields, such as `"remaining"` or `"speed"`. Durations are
+/// written in seconds (e.g. `"2.5"`), and the other fields as their usual string form.
+/// [`DecayEntityCommandsExt::set_decay_path`] reads the view from the entity, applies the
+/// change and writes it back:
+///
+/// - changing the `remaining` or `elapsed` time moves the timer, keeping its duration;
+/// - changing the `duration` keeps the elapsed time;
+/// - `paused` pauses or resumes the decay as the `"reflect"` pause source;
+/// - `speed` is stored in the `DecayModifiers` of the entity under the `"reflect"` key.
+///
+/// [`DecayEntityCommandsExt::set_decay_path`]: crate::DecayEntityCommandsExt::set_decay_path
+#[derive(Reflect, Clone, Copy, PartialEq, Debug)]
+pub struct DecayControl {
+    /// The time left before the decay completes.
+    pub remaining: Duration,
+    /// The time elapsed since the decay started.
+    pub elapsed: Duration,
+    /// The total duration of the decay.
+    pub duration: Duration,
+    /// Whether the decay is paused from a reflection path.
+    pub paused: bool,
+    /// The multiplier applied to the decay speed from a reflection path.
+    pub speed: f32,
+}
+
+impl DecayControl {
+    /// Reads the view of the decay of the track `T` of the entity, if it is decaying.
+    pub fn read<T: Component>(entity: &EntityWorldMut) -> Option<Self> {
+        let timer = entity.get::<DecayTimer<T>>()?;
+
+        Some(Self {
+            remaining: timer.remaining(),
+            elapsed: timer.elapsed(),
+            duration: timer.duration(),
+            paused: entity
+                .get::<DecayPauseSources>()
+                .is_some_and(|sources| sources.contains(PATH_SOURCE)),
+            speed: entity
+                .get::<DecayModifiers>()
+                .and_then(|modifiers| modifiers.get(PATH_SOURCE))
+                .unwrap_or(1.0),
+        })
+    }
+
+    /// Writes the changes made to the view back to the decay of the track `T` of the entity.
+    fn write<T: Component>(self, previous: Self, entity: &mut EntityWorldMut) {
+        if let Some(mut timer) = entity.get_mut::<DecayTimer<T>>() {
+            let elapsed = if self.remaining != previous.remaining {
+                self.duration.saturating_sub(self.remaining)
+            } else {
+                self.elapsed
+            };
+
+            if self.duration != previous.duration {
+                timer.set_duration(self.duration);
+            }
+
+            if elapsed != previous.elapsed || self.duration != previous.duration {
+                timer.restart_at(elapsed.min(self.duration));
+            }
+        }
+
+        if self.paused && !previous.paused {
+            pause::pause(entity, PATH_SOURCE.into());
+        } else if !self.paused && previous.paused {
+            pause::resume(entity, PATH_SOURCE);
+        }
+
+        if self.speed != previous.speed {
+            if entity.get::<DecayModifiers>().is_none() {
+                entity.insert(DecayModifiers::default());
+            }
+
+            if let Some(mut modifiers) = entity.get_mut::<DecayModifiers>() {
+                modifiers.insert(PATH_SOURCE, self.speed);
+            }
+        }
+    }
+}
+
+/// Sets the field of the `DecayControl` of the track `T` of the entity at the given path to
+/// the value parsed from the given string.
+pub fn set_decay_path<T: Component>(
+    entity: &mut EntityWorldMut,
+    path: &str,
+    value: &str,
+) -> Result<(), DecayPathError> {
+    let previous = DecayControl::read::<T>(entity).ok_or(DecayPathError::NotDecaying)?;
+
+    let mut control = previous;
+    let field = control
+        .reflect_path_mut(path)
+        .map_err(|error| DecayPathError::Path(error.to_string()))?;
+
+    let invalid = || DecayPathError::Value(value.to_string());
+    if let Some(duration) = field.try_downcast_mut::<Duration>() {
+        let seconds: f64 = value.parse().map_err(|_| invalid())?;
+        *duration = Duration::try_from_secs_f64(seconds).map_err(|_| invalid())?;
+    } else if let Some(speed) = field.try_downcast_mut::<f32>() {
+        *speed = value.parse().map_err(|_| invalid())?;
+    } else if let Some(paused) = field.try_downcast_mut::<bool>() {
+        *paused = value.parse().map_err(|_| invalid())?;
+    } else {
+        return Err(invalid());
+    }
+
+    control.write::<T>(previous, entity);
+    Ok(())
+}
+
+/// Error raised while setting a reflection path of the decay of an entity.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum DecayPathError {
+    /// The entity is not decaying on the track.
+    NotDecaying,
+    /// The path does not lead to a field of the `DecayControl`.
+    Path(String),
+    /// The value could not be parsed for the field.
+    Value(String),
+}
+
+impl fmt::Display for DecayPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotDecaying => write!(f, "the entity is not decaying"),
+            Self::Path(error) => write!(f, "invalid decay path: {error}"),
+            Self::Value(value) => write!(f, "invalid decay value: {value}"),
+        }
+    }
+}
+
+impl Error for DecayPathError {}
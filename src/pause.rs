@@ -0,0 +1,197 @@
+use crate::{
+    group, trigger_started, DecayDisabled, DecayEntityCommandsExt, DecayFocusSuspend, DecayFrozen,
+    DecayPauseReason, DecayPaused, DecayStartedBuffer, DecayTag, DecayTimer, NeverDecay,
+};
+use bevy::prelude::*;
+use std::{borrow::Cow, collections::BTreeMap};
+
+/// Sources pausing the decay of an entity, each counted by how many times it paused it.
+///
+/// Several systems (container logic, a global pause, a freeze spell) may pause the same entity,
+/// and the decay only resumes once every one of them released it. The entity bears the
+/// `DecayFrozen` component as long as any source is left.
+///
+/// The sources are managed through the `pause_decay` and `resume_decay` commands of the
+/// [`DecayEntityCommandsExt`](crate::DecayEntityCommandsExt) trait.
+#[derive(Component, Clone, Default, Debug)]
+pub struct DecayPauseSources {
+    /// The number of pauses, by source.
+    sources: BTreeMap<Cow<'static, str>, u32>,
+}
+
+impl DecayPauseSources {
+    /// Returns the number of pauses held by the given source.
+    pub fn count(&self, source: &str) -> u32 {
+        self.sources.get(source).copied().unwrap_or(0)
+    }
+
+    /// Checks if the given source holds any pause.
+    pub fn contains(&self, source: &str) -> bool {
+        self.sources.contains_key(source)
+    }
+
+    /// Checks if no source holds any pause.
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// Iterates over the sources as pairs of source and number of pauses.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.sources
+            .iter()
+            .map(|(source, count)| (source.as_ref(), *count))
+    }
+
+    /// Adds a pause of the given source.
+    pub(crate) fn add(&mut self, source: Cow<'static, str>) {
+        *self.sources.entry(source).or_default() += 1;
+    }
+
+    /// Releases a pause of the given source, if it holds any.
+    pub(crate) fn release(&mut self, source: &str) {
+        let Some(count) = self.sources.get_mut(source) else {
+            return;
+        };
+
+        *count -= 1;
+        if *count == 0 {
+            self.sources.remove(source);
+        }
+    }
+}
+
+/// Adds a pause of the given source to the entity, freezing its decay.
+pub(crate) fn pause(entity: &mut EntityWorldMut, source: Cow<'static, str>) {
+    if entity.get::<DecayPauseSources>().is_none() {
+        entity.insert(DecayPauseSources::default());
+    }
+
+    if let Some(mut sources) = entity.get_mut::<DecayPauseSources>() {
+        sources.add(source);
+    }
+
+    if !entity.contains::<DecayFrozen>() {
+        entity.insert(DecayFrozen);
+    }
+}
+
+/// Returns the reason of the pause of an entity frozen by the given sources.
+///
+/// The entity is frozen once its first source pauses it, so that source decides the reason.
+pub(crate) fn freeze_reason(sources: Option<&DecayPauseSources>) -> DecayPauseReason {
+    if sources.is_some_and(|sources| sources.contains(group::GROUP_PAUSE_SOURCE)) {
+        DecayPauseReason::GroupPaused
+    } else {
+        DecayPauseReason::Frozen
+    }
+}
+
+/// Releases a pause of the given source from the entity, resuming its decay once no source is
+/// left.
+pub(crate) fn resume(entity: &mut EntityWorldMut, source: &str) {
+    let Some(mut sources) = entity.get_mut::<DecayPauseSources>() else {
+        return;
+    };
+
+    sources.release(source);
+
+    if sources.is_empty() {
+        entity.remove::<(DecayPauseSources, DecayFrozen)>();
+    }
+}
+
+/// Component pausing the decay of the target entity for as long as it exists.
+///
+/// Inserting it pauses the decay of the target, and removing it, replacing it with a guard of
+/// another target, or despawning the entity bearing it, resumes the decay automatically.
+/// Placed on the entity of a temporary effect (e.g. a freeze spell), it can never leak a
+/// permanently paused item, even when the effect is cleaned up abnormally. Each guard is a
+/// pause source of its own in `DecayPauseSources`.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct DecayPauseGuard(pub Entity);
+
+/// Returns the pause source of the guard borne by the given entity.
+fn guard_source(guard: Entity) -> Cow<'static, str> {
+    Cow::Owned(format!("pause guard {guard}"))
+}
+
+/// System that pauses the decay of the target of a `DecayPauseGuard` when it is inserted,
+/// including when it replaces a guard of another target.
+pub(crate) fn handle_pause_guard_inserted(
+    trigger: Trigger<OnInsert, DecayPauseGuard>,
+    mut commands: Commands,
+    query: Query<&DecayPauseGuard>,
+) {
+    let guard = trigger.entity();
+    let Ok(&DecayPauseGuard(target)) = query.get(guard) else {
+        return;
+    };
+
+    if let Some(mut target) = commands.get_entity(target) {
+        target.pause_decay(guard_source(guard));
+    }
+}
+
+/// System that resumes the decay of the target of a `DecayPauseGuard` when it is removed or
+/// replaced, including when the entity bearing it is despawned.
+pub(crate) fn handle_pause_guard_replaced(
+    trigger: Trigger<OnReplace, DecayPauseGuard>,
+    mut commands: Commands,
+    query: Query<&DecayPauseGuard>,
+) {
+    let guard = trigger.entity();
+    let Ok(&DecayPauseGuard(target)) = query.get(guard) else {
+        return;
+    };
+
+    if let Some(mut target) = commands.get_entity(target) {
+        target.resume_decay(guard_source(guard));
+    }
+}
+
+/// System that reports the running decays of the track `T` as paused while the virtual clock
+/// is paused or the app is suspended by `DecayFocusSuspend`, and as resumed afterwards.
+///
+/// The timers are left untouched, as they do not progress meanwhile anyway.
+pub(crate) fn report_global_pause<T: Component>(
+    mut commands: Commands,
+    mut paused: Local<bool>,
+    time: Res<Time<Virtual>>,
+    suspend: Option<Res<DecayFocusSuspend>>,
+    mut started_buffer: Option<ResMut<DecayStartedBuffer<T>>>,
+    query: Query<
+        (Entity, &DecayTimer<T>, Option<&DecayTag>),
+        (
+            With<T>,
+            Without<DecayFrozen>,
+            Without<NeverDecay>,
+            Without<DecayDisabled<T>>,
+        ),
+    >,
+) {
+    let now_paused = time.is_paused() || suspend.is_some_and(|suspend| suspend.suspended());
+    if now_paused == *paused {
+        return;
+    }
+    *paused = now_paused;
+
+    for (entity, timer, tag) in query.iter().filter(|(_, timer, _)| !timer.paused()) {
+        if now_paused {
+            commands.trigger(DecayPaused::<T>::new(
+                entity,
+                timer.remaining(),
+                timer.fraction(),
+                DecayPauseReason::GlobalPause,
+            ));
+        } else {
+            trigger_started(
+                &mut commands,
+                started_buffer.as_deref_mut(),
+                entity,
+                timer.remaining(),
+                true,
+                tag,
+            );
+        }
+    }
+}
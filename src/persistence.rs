@@ -0,0 +1,235 @@
+use crate::{Decay, DecayState, DecayTimer};
+use bevy::{
+    ecs::entity::{EntityHashMap, EntityHashSet},
+    prelude::*,
+    tasks::{block_on, IoTaskPool, Task},
+};
+use std::{future::Future, marker::PhantomData, sync::Arc, time::Duration};
+
+/// Durable storage for the decay states of the track `T`, such as a database.
+///
+/// Writes are batched and debounced by the [`DecayPersistencePlugin`], and run as async tasks
+/// on the `IoTaskPool`, so implementations may freely await their storage (sqlx, sled, files).
+/// Entities are not stable across runs, so stores usually map them to durable identifiers,
+/// e.g. through a lookup filled when spawning persisted entities.
+pub trait DecayStore: Send + Sync + 'static {
+    /// Durably writes a batch of decay states.
+    fn write(&self, batch: DecayStoreBatch) -> impl Future<Output = ()> + Send;
+}
+
+/// Batch of decay state changes written to a `DecayStore` at once.
+#[derive(Clone, Default, Debug)]
+pub struct DecayStoreBatch {
+    /// The entities whose decay state changed, with their current state.
+    pub saved: Vec<(Entity, DecayState)>,
+    /// The entities that stopped decaying (completed, cancelled or despawned).
+    pub removed: Vec<Entity>,
+}
+
+impl DecayStoreBatch {
+    /// Checks if the batch holds no change.
+    pub fn is_empty(&self) -> bool {
+        self.saved.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Plugin persisting the decay states of the track `T` (`Decay` by default) to a `DecayStore`.
+///
+/// Entities whose decay changed state (started, paused, resumed, stopped or given a new
+/// duration) are marked dirty, and the dirty states are written in a single batch once the
+/// debounce period has elapsed since the last write. A new write is only scheduled once the
+/// previous one has finished, so batches reach the store in order.
+///
+/// When an `AppExit` event is sent, the `DecaySnapshotRequested` event is triggered, then every
+/// remaining decay state is written before shutting down, so graceful stops keep the in-flight
+/// decays.
+pub struct DecayPersistencePlugin<S: DecayStore, T: Component = Decay> {
+    /// The store the decay states are written to.
+    store: Arc<S>,
+    /// The minimum duration between two writes.
+    debounce: Duration,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<S: DecayStore, T: Component> DecayPersistencePlugin<S, T> {
+    /// Creates a new `DecayPersistencePlugin` writing to the given store every 5 seconds.
+    pub fn new(store: S) -> Self {
+        Self {
+            store: Arc::new(store),
+            debounce: Duration::from_secs(5),
+            track: PhantomData,
+        }
+    }
+
+    /// Sets the minimum duration between two writes.
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+}
+
+impl<S: DecayStore, T: Component> Plugin for DecayPersistencePlugin<S, T> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(DecayPersistence::<S, T> {
+            store: self.store.clone(),
+            debounce: Timer::new(self.debounce, TimerMode::Repeating),
+            known: EntityHashMap::default(),
+            dirty: EntityHashSet::default(),
+            removed: EntityHashSet::default(),
+            task: None,
+            track: PhantomData,
+        })
+        .add_event::<DecaySnapshotRequested<T>>()
+        .add_systems(
+            Last,
+            (
+                persist_decay::<S, T>,
+                request_snapshot_on_exit::<T>,
+                flush_on_exit::<S, T>,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Event triggered right before the app exits, before the final decay states of the track `T`
+/// are written to the `DecayStore`.
+///
+/// Lets the game capture any state of its own alongside the decays before shutting down, or
+/// settle the decays themselves (e.g. pausing them), as the states are only read once its
+/// observers have run.
+#[derive(Event)]
+pub struct DecaySnapshotRequested<T: Component = Decay>(PhantomData<T>);
+
+impl<T: Component> Default for DecaySnapshotRequested<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Resource tracking the dirty decay states of the track `T` until they are written.
+#[derive(Resource)]
+struct DecayPersistence<S: DecayStore, T: Component> {
+    /// The store the decay states are written to.
+    store: Arc<S>,
+    /// The timer debouncing the writes.
+    debounce: Timer,
+    /// The pause status and duration of each decay when it was last marked dirty.
+    known: EntityHashMap<(bool, Duration)>,
+    /// The entities whose decay state changed since the last write.
+    dirty: EntityHashSet,
+    /// The entities that stopped decaying since the last write.
+    removed: EntityHashSet,
+    /// The write in progress, if any.
+    task: Option<Task<()>>,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+/// System that marks decays changing state as dirty and schedules the debounced writes.
+///
+/// Running timers change every frame, so only their pause status and duration are compared.
+fn persist_decay<S: DecayStore, T: Component>(
+    time: Res<Time>,
+    mut persistence: ResMut<DecayPersistence<S, T>>,
+    mut removed: RemovedComponents<DecayTimer<T>>,
+    changed: Query<(Entity, &DecayTimer<T>), Changed<DecayTimer<T>>>,
+    timers: Query<&DecayTimer<T>>,
+) {
+    let persistence = &mut *persistence;
+
+    // Mark the started, paused, resumed and resized decays as dirty.
+    for (entity, timer) in changed.iter() {
+        let state = (timer.paused(), timer.duration());
+        if persistence.known.insert(entity, state) != Some(state) {
+            persistence.dirty.insert(entity);
+        }
+    }
+
+    // Mark the stopped decays as removed.
+    for entity in removed.read() {
+        persistence.known.remove(&entity);
+        persistence.dirty.remove(&entity);
+        persistence.removed.insert(entity);
+    }
+
+    persistence.debounce.tick(time.delta());
+
+    // Wait for the debounce period and for the previous write to finish.
+    if !persistence.debounce.finished()
+        || persistence
+            .task
+            .as_ref()
+            .is_some_and(|task| !task.is_finished())
+    {
+        return;
+    }
+
+    let batch = DecayStoreBatch {
+        saved: persistence
+            .dirty
+            .drain()
+            .filter_map(|entity| Some((entity, DecayState::of(timers.get(entity).ok()?))))
+            .collect(),
+        // Entities decaying again since they stopped are saved instead.
+        removed: persistence
+            .removed
+            .drain()
+            .filter(|&entity| !timers.contains(entity))
+            .collect(),
+    };
+
+    if batch.is_empty() {
+        persistence.task = None;
+        return;
+    }
+
+    // Hand the batch to the store on the IO task pool.
+    let store = persistence.store.clone();
+    persistence.task = Some(IoTaskPool::get().spawn(async move { store.write(batch).await }));
+}
+
+/// System that triggers the `DecaySnapshotRequested` event of the track `T` before the app
+/// exits.
+fn request_snapshot_on_exit<T: Component>(mut commands: Commands, mut exit: EventReader<AppExit>) {
+    if exit.is_empty() {
+        return;
+    }
+    exit.clear();
+
+    commands.trigger(DecaySnapshotRequested::<T>::default());
+}
+
+/// System that writes every remaining decay state before the app exits.
+fn flush_on_exit<S: DecayStore, T: Component>(
+    mut exit: EventReader<AppExit>,
+    mut persistence: ResMut<DecayPersistence<S, T>>,
+    timers: Query<(Entity, &DecayTimer<T>), With<T>>,
+) {
+    if exit.is_empty() {
+        return;
+    }
+    exit.clear();
+
+    // Wait for the write in progress, so the final batch is the last to reach the store.
+    if let Some(task) = persistence.task.take() {
+        block_on(task);
+    }
+
+    let batch = DecayStoreBatch {
+        saved: timers
+            .iter()
+            .map(|(entity, timer)| (entity, DecayState::of(timer)))
+            .collect(),
+        removed: persistence
+            .removed
+            .drain()
+            .filter(|&entity| !timers.contains(entity))
+            .collect(),
+    };
+    persistence.dirty.clear();
+
+    // The app is about to exit, so the batch is written right away.
+    block_on(persistence.store.write(batch));
+}
@@ -0,0 +1,126 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Plugin ticking the `PredictedDecay` countdowns of multiplayer clients.
+///
+/// The decay stays under the authority of the server: clients only display a countdown ticking
+/// locally, reconciled against the server clock and expiries they receive.
+pub struct PredictedDecayPlugin;
+
+impl Plugin for PredictedDecayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DecayServerClock>()
+            .add_systems(PreUpdate, tick_predicted_decays);
+    }
+}
+
+/// Estimate of the server clock on a client, kept in sync by the server timestamps it receives.
+#[derive(Resource, Debug)]
+pub struct DecayServerClock {
+    /// How much of each measured offset is applied, from 0 to 1, smoothing out network jitter.
+    pub smoothing: f32,
+    /// The estimated offset of the server clock from the local clock, in seconds.
+    offset: Option<f64>,
+}
+
+impl Default for DecayServerClock {
+    fn default() -> Self {
+        Self {
+            smoothing: 0.1,
+            offset: None,
+        }
+    }
+}
+
+impl DecayServerClock {
+    /// Synchronizes the clock with a server timestamp received at the given local time, such as
+    /// `Time::elapsed`.
+    ///
+    /// The first timestamp sets the clock, and later ones nudge it by the `smoothing` factor.
+    pub fn sync(&mut self, server_now: Duration, local_now: Duration) {
+        let measured = server_now.as_secs_f64() - local_now.as_secs_f64();
+        let smoothing = f64::from(self.smoothing.clamp(0.0, 1.0));
+
+        self.offset = Some(match self.offset {
+            Some(offset) => offset + (measured - offset) * smoothing,
+            None => measured,
+        });
+    }
+
+    /// Returns the estimated server time at the given local time, or `None` before the first
+    /// synchronization.
+    pub fn now(&self, local_now: Duration) -> Option<Duration> {
+        let offset = self.offset?;
+        Some(Duration::from_secs_f64(
+            (local_now.as_secs_f64() + offset).max(0.0),
+        ))
+    }
+}
+
+/// Countdown of a decay owned by the server, displayed by a client.
+///
+/// The countdown ticks locally every frame, and is steered towards the remaining time derived
+/// from the `server_expiry` and the `DecayServerClock`, so it stays smooth while corrections
+/// arrive. Corrections beyond the `snap` threshold are applied at once.
+#[derive(Component, Debug)]
+pub struct PredictedDecay {
+    /// The time of the server clock at which the decay expires, updated by the server.
+    pub server_expiry: Duration,
+    /// The error beyond which the countdown jumps to the corrected remaining time.
+    pub snap: Duration,
+    /// The fraction of the error corrected per second.
+    pub correction_rate: f32,
+    /// The displayed remaining time, or `None` before the first estimate.
+    remaining: Option<Duration>,
+}
+
+impl PredictedDecay {
+    /// Creates a new `PredictedDecay` expiring at the given server time.
+    pub fn new(server_expiry: Duration) -> Self {
+        Self {
+            server_expiry,
+            snap: Duration::from_secs(1),
+            correction_rate: 2.0,
+            remaining: None,
+        }
+    }
+
+    /// Returns the displayed remaining time, or `None` until the server clock is synchronized.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.remaining
+    }
+
+    /// Checks if the displayed countdown has reached zero.
+    pub fn expired(&self) -> bool {
+        self.remaining.is_some_and(|remaining| remaining.is_zero())
+    }
+}
+
+/// System that ticks the predicted countdowns, steering them towards the server expiries.
+fn tick_predicted_decays(
+    time: Res<Time>,
+    clock: Res<DecayServerClock>,
+    mut query: Query<&mut PredictedDecay>,
+) {
+    let Some(server_now) = clock.now(time.elapsed()) else {
+        return;
+    };
+
+    for mut predicted in query.iter_mut() {
+        let target = predicted.server_expiry.saturating_sub(server_now);
+
+        let remaining = match predicted.remaining {
+            // Tick locally, then correct part of the error with the server estimate.
+            Some(remaining) if remaining.abs_diff(target) < predicted.snap => {
+                let remaining = remaining.saturating_sub(time.delta()).as_secs_f32();
+                let correction = (predicted.correction_rate * time.delta().as_secs_f32()).min(1.0);
+                let corrected = remaining + (target.as_secs_f32() - remaining) * correction;
+                Duration::from_secs_f32(corrected.max(0.0))
+            }
+            // Start from, or jump to, the server estimate.
+            _ => target,
+        };
+
+        predicted.remaining = Some(remaining);
+    }
+}
@@ -0,0 +1,5 @@
+//! Opt-in presets implementing common decay setups on top of the `DecayPlugin`.
+
+pub mod corpse;
+pub mod field;
+pub mod janitor;
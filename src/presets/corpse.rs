@@ -0,0 +1,144 @@
+//! Corpse decay, going through the familiar fresh, rotting and bones stages before the corpse
+//! is despawned.
+//!
+//! Add the [`CorpseDecayPlugin`] and spawn entities with the [`Corpse`] component. Each stage
+//! is a decay track of its own, chained into the next one, and the [`CorpseStageChanged`]
+//! event is triggered whenever a corpse enters a stage.
+
+use crate::{DecayCompleted, DecayDuration, DecayPlugin, DecayThen};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Plugin decaying corpses through the fresh, rotting and bones stages.
+///
+/// The durations of the stages are configured by the [`CorpseDecay`] resource, which is
+/// initialized with its default value unless inserted beforehand.
+pub struct CorpseDecayPlugin;
+
+impl Plugin for CorpseDecayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CorpseDecay>()
+            .add_event::<CorpseStageChanged>();
+
+        app.add_plugins((
+            DecayPlugin::<CorpseFresh>::new()
+                .without_actions()
+                .then::<CorpseRotting>(),
+            DecayPlugin::<CorpseRotting>::new()
+                .without_actions()
+                .then::<CorpseBones>(),
+            DecayPlugin::<CorpseBones>::new().without_actions(),
+        ));
+
+        app.add_observer(handle_corpse_added)
+            .add_observer(handle_stage::<CorpseFresh>)
+            .add_observer(handle_stage::<CorpseRotting>)
+            .add_observer(handle_stage::<CorpseBones>)
+            .add_observer(handle_corpse_decayed);
+    }
+}
+
+/// Durations of the stages of corpse decay.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct CorpseDecay {
+    /// The duration of the fresh stage.
+    pub fresh: DecayDuration,
+    /// The duration of the rotting stage.
+    pub rotting: DecayDuration,
+    /// The duration of the bones stage, after which the corpse is despawned.
+    pub bones: DecayDuration,
+}
+
+impl Default for CorpseDecay {
+    fn default() -> Self {
+        Self {
+            fresh: DecayDuration::new(Duration::from_secs(60)),
+            rotting: DecayDuration::new(Duration::from_secs(5 * 60)),
+            bones: DecayDuration::new(Duration::from_secs(10 * 60)),
+        }
+    }
+}
+
+/// Marker component for corpses, starting their decay at the fresh stage once added.
+#[derive(Component, Default, Debug)]
+pub struct Corpse;
+
+/// Stage of corpse decay.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum CorpseStage {
+    /// The corpse has just been created.
+    Fresh,
+    /// The corpse is rotting.
+    Rotting,
+    /// Only the bones of the corpse are left.
+    Bones,
+}
+
+/// Marker component of the decay track for the fresh stage.
+#[derive(Component, Default, Debug)]
+pub struct CorpseFresh;
+
+/// Marker component of the decay track for the rotting stage.
+#[derive(Component, Default, Debug)]
+pub struct CorpseRotting;
+
+/// Marker component of the decay track for the bones stage.
+#[derive(Component, Default, Debug)]
+pub struct CorpseBones;
+
+/// Stage markers, mapping each decay track to its stage.
+trait CorpseStageMarker: Component {
+    /// The stage of the decay track.
+    const STAGE: CorpseStage;
+}
+
+impl CorpseStageMarker for CorpseFresh {
+    const STAGE: CorpseStage = CorpseStage::Fresh;
+}
+
+impl CorpseStageMarker for CorpseRotting {
+    const STAGE: CorpseStage = CorpseStage::Rotting;
+}
+
+impl CorpseStageMarker for CorpseBones {
+    const STAGE: CorpseStage = CorpseStage::Bones;
+}
+
+/// Event triggered when a corpse enters a stage of its decay.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CorpseStageChanged {
+    /// The corpse entering the stage.
+    pub entity: Entity,
+    /// The stage entered by the corpse.
+    pub stage: CorpseStage,
+}
+
+/// System that starts the decay of corpses at the fresh stage when the `Corpse` component
+/// is added, chaining the following stages.
+fn handle_corpse_added(
+    trigger: Trigger<OnAdd, Corpse>,
+    mut commands: Commands,
+    config: Res<CorpseDecay>,
+) {
+    commands.entity(trigger.entity()).insert((
+        DecayThen::<CorpseFresh, CorpseRotting>::new(CorpseRotting, config.rotting),
+        DecayThen::<CorpseRotting, CorpseBones>::new(CorpseBones, config.bones),
+        config.fresh,
+        CorpseFresh,
+    ));
+}
+
+/// System that triggers the `CorpseStageChanged` event when a corpse enters the stage `S`.
+fn handle_stage<S: CorpseStageMarker>(trigger: Trigger<OnAdd, S>, mut commands: Commands) {
+    commands.trigger(CorpseStageChanged {
+        entity: trigger.entity(),
+        stage: S::STAGE,
+    });
+}
+
+/// System that despawns corpses that have completed the bones stage.
+fn handle_corpse_decayed(trigger: Trigger<DecayCompleted<CorpseBones>>, mut commands: Commands) {
+    for &entity in trigger.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,78 @@
+//! Field items, such as fire, poison or energy fields, decaying away over a duration while
+//! periodically ticking their effect.
+//!
+//! Add the [`FieldDecayPlugin`] and spawn entities with [`Field::bundle`]. While the field is
+//! decaying, the [`FieldTick`] event is triggered, targeted at the field, every tick interval
+//! so the game can apply damage or any other effect.
+
+use crate::{Decay, DecayAction, DecayDuration, DecayFrozen, DecayPlugin, DecayingSet, NeverDecay};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Plugin ticking the effect of decaying fields.
+///
+/// The default `DecayPlugin` is added if it was not already.
+pub struct FieldDecayPlugin;
+
+impl Plugin for FieldDecayPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<DecayPlugin>() {
+            app.add_plugins(DecayPlugin::default());
+        }
+
+        app.add_event::<FieldTick>()
+            .add_systems(PreUpdate, tick_fields.in_set(DecayingSet::Tick));
+    }
+}
+
+/// Component ticking the effect of a field at a fixed interval while it decays.
+#[derive(Component, Debug)]
+pub struct Field {
+    /// The timer counting down the interval between ticks.
+    timer: Timer,
+}
+
+impl Field {
+    /// Creates a new `Field` ticking its effect every `interval`.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            timer: Timer::new(interval, TimerMode::Repeating),
+        }
+    }
+
+    /// Creates a bundle of a field ticking its effect every `interval` and despawned once
+    /// it has decayed for the given duration.
+    pub fn bundle(
+        duration: DecayDuration,
+        interval: Duration,
+    ) -> (Self, Decay, DecayDuration, DecayAction) {
+        (Self::new(interval), Decay, duration, DecayAction::Despawn)
+    }
+}
+
+/// Event triggered, targeted at the field, every tick interval of a decaying field.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct FieldTick {
+    /// The field ticking its effect.
+    pub entity: Entity,
+}
+
+/// System that ticks the effect of decaying fields.
+fn tick_fields(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<
+        (Entity, &mut Field),
+        (With<Decay>, Without<DecayFrozen>, Without<NeverDecay>),
+    >,
+) {
+    for (entity, mut field) in query.iter_mut() {
+        // Progress the field timer based on the time elapsed since the last frame.
+        field.timer.tick(time.delta());
+
+        // Trigger a tick for every interval elapsed, even within a single frame.
+        for _ in 0..field.timer.times_finished_this_tick() {
+            commands.trigger_targets(FieldTick { entity }, entity);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Floor-item cleanup, automatically attaching decay to items dropped on the floor and
+//! limiting how many of them can pile up on a single tile.
+//!
+//! Add the [`FloorItemJanitorPlugin`] and mark dropped items with the [`FloorItem`] component.
+//! Items without a decay of their own receive the default decay of the [`FloorItemPolicy`],
+//! and the items closest to expiring are despawned whenever a tile holds more than allowed.
+
+use crate::{Decay, DecayAction, DecayDuration, DecayPlugin, DecayTimer, DecayingSet, NeverDecay};
+use bevy::{prelude::*, utils::HashMap};
+use std::time::Duration;
+
+/// Plugin attaching decay to floor items and enforcing the per-tile limit.
+///
+/// The policy is configured by the [`FloorItemPolicy`] resource, which is initialized with its
+/// default value unless inserted beforehand. The default `DecayPlugin` is added if it was not
+/// already.
+pub struct FloorItemJanitorPlugin;
+
+impl Plugin for FloorItemJanitorPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<DecayPlugin>() {
+            app.add_plugins(DecayPlugin::default());
+        }
+
+        app.init_resource::<FloorItemPolicy>()
+            .add_observer(handle_floor_item_added)
+            .add_systems(PreUpdate, enforce_tile_limit.after(DecayingSet::Complete));
+    }
+}
+
+/// Policy of the floor-item janitor.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct FloorItemPolicy {
+    /// The size of a tile, used to group floor items by their translation.
+    pub tile_size: f32,
+    /// The maximum number of floor items on a single tile, or `None` for no limit.
+    pub max_per_tile: Option<usize>,
+    /// The decay attached to floor items dropped without a decay of their own.
+    pub duration: DecayDuration,
+}
+
+impl Default for FloorItemPolicy {
+    fn default() -> Self {
+        Self {
+            tile_size: 1.0,
+            max_per_tile: Some(10),
+            duration: DecayDuration::new(Duration::from_secs(5 * 60)),
+        }
+    }
+}
+
+impl FloorItemPolicy {
+    /// Returns the tile containing the given translation.
+    pub fn tile(&self, translation: Vec3) -> IVec3 {
+        (translation / self.tile_size).floor().as_ivec3()
+    }
+}
+
+/// Marker component for items lying on the floor, cleaned up by the janitor.
+#[derive(Component, Default, Debug)]
+#[require(Transform)]
+pub struct FloorItem;
+
+/// System that attaches the default decay of the policy to floor items dropped without a decay
+/// of their own, unless their decay is blocked by `NeverDecay`.
+fn handle_floor_item_added(
+    trigger: Trigger<OnAdd, FloorItem>,
+    mut commands: Commands,
+    policy: Res<FloorItemPolicy>,
+    query: Query<(), Or<(With<Decay>, With<NeverDecay>)>>,
+) {
+    let entity = trigger.entity();
+    if query.contains(entity) {
+        return;
+    }
+
+    commands
+        .entity(entity)
+        .insert((Decay, policy.duration, DecayAction::Despawn));
+}
+
+/// System that despawns the floor items closest to expiring on tiles holding more items than
+/// the policy allows.
+///
+/// Items blocked by `NeverDecay` count towards the limit, but are never despawned.
+fn enforce_tile_limit(
+    mut commands: Commands,
+    policy: Res<FloorItemPolicy>,
+    query: Query<(Entity, Ref<Transform>, Option<&DecayTimer>, Has<NeverDecay>), With<FloorItem>>,
+) {
+    let Some(max_per_tile) = policy.max_per_tile else {
+        return;
+    };
+
+    // Only regroup the items when one of them was dropped or moved.
+    if !policy.is_changed()
+        && !query
+            .iter()
+            .any(|(_, transform, ..)| transform.is_changed())
+    {
+        return;
+    }
+
+    // Group the items by tile, counting all of them but only collecting those that can go.
+    let mut tiles: HashMap<IVec3, (usize, Vec<(Entity, Duration)>)> = HashMap::new();
+    for (entity, transform, timer, never) in query.iter() {
+        let (count, items) = tiles.entry(policy.tile(transform.translation)).or_default();
+        *count += 1;
+
+        if !never {
+            // Items without a running decay are the last to go.
+            let remaining = timer.map_or(Duration::MAX, |timer| timer.remaining());
+            items.push((entity, remaining));
+        }
+    }
+
+    for (count, items) in tiles.values_mut() {
+        if *count <= max_per_tile {
+            continue;
+        }
+
+        items.sort_by_key(|&(_, remaining)| remaining);
+
+        let excess = (*count - max_per_tile).min(items.len());
+        for &(entity, _) in &items[..excess] {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
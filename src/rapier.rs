@@ -0,0 +1,85 @@
+use crate::DecayModifiers;
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Sleeping;
+
+/// Gates the decay of an entity on its physics body being asleep.
+///
+/// While the rigid body is awake, the decay of the entity does not progress on any track, so
+/// thrown loot does not rot mid-air. With [`DecayRestMode::UntilFirstRest`], the gate is removed
+/// once the body first falls asleep, so the decay effectively starts then and keeps going even
+/// if the body is woken up again.
+#[derive(Component, Clone, Copy, Default, Debug)]
+#[require(Sleeping)]
+pub struct DecayWhenAtRest {
+    /// How the gate applies once the body has fallen asleep.
+    pub mode: DecayRestMode,
+}
+
+impl DecayWhenAtRest {
+    /// Creates a new `DecayWhenAtRest` with the given mode.
+    pub fn new(mode: DecayRestMode) -> Self {
+        Self { mode }
+    }
+}
+
+/// How a `DecayWhenAtRest` gate applies once the physics body has fallen asleep.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub enum DecayRestMode {
+    /// The decay only progresses while the body is asleep.
+    #[default]
+    WhileAtRest,
+    /// The decay waits for the body to fall asleep once, then progresses regardless.
+    UntilFirstRest,
+}
+
+/// The key of the modifier holding back the decay of awake bodies.
+const AT_REST_MODIFIER: &str = "at rest";
+
+/// System that holds back the decay of entities gated by `DecayWhenAtRest` while their physics
+/// body is awake.
+pub(crate) fn apply_rest_gate(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        Ref<DecayWhenAtRest>,
+        Ref<Sleeping>,
+        Option<&mut DecayModifiers>,
+    )>,
+) {
+    for (entity, gate, sleeping, modifiers) in query.iter_mut() {
+        if !gate.is_changed() && !sleeping.is_changed() {
+            continue;
+        }
+
+        match (sleeping.sleeping, modifiers) {
+            // The body fell asleep, so the decay progresses again.
+            (true, modifiers) => {
+                if let Some(mut modifiers) = modifiers {
+                    modifiers.remove(AT_REST_MODIFIER);
+                }
+                if gate.mode == DecayRestMode::UntilFirstRest {
+                    commands.entity(entity).remove::<DecayWhenAtRest>();
+                }
+            }
+            // The body is awake, so the decay is held back.
+            (false, Some(mut modifiers)) => {
+                modifiers.insert(AT_REST_MODIFIER, 0.0);
+            }
+            (false, None) => {
+                let mut modifiers = DecayModifiers::default();
+                modifiers.insert(AT_REST_MODIFIER, 0.0);
+                commands.entity(entity).insert(modifiers);
+            }
+        }
+    }
+}
+
+/// System that lets the decay progress again once the `DecayWhenAtRest` gate is removed.
+pub(crate) fn handle_rest_gate_removed(
+    trigger: Trigger<OnRemove, DecayWhenAtRest>,
+    mut query: Query<&mut DecayModifiers>,
+) {
+    if let Ok(mut modifiers) = query.get_mut(trigger.entity()) {
+        modifiers.remove(AT_REST_MODIFIER);
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{focus, region::InDecayRegion, DecayFocusSuspend, DecayWhenIdle};
+use bevy::{
+    ecs::{entity::EntityHashMap, query::QueryData, system::SystemId},
+    prelude::*,
+};
+use std::{borrow::Cow, collections::BTreeMap, time::Duration};
+
+/// Temporary multiplier applied to the decay speed of an entity.
+///
+/// While present, the elapsed time of every decay track of the entity is scaled by the
+/// `multiplier` (e.g. `2.0` decays twice as fast, `0.5` half as fast). The effect removes
+/// itself once its own duration has elapsed, which covers effects like an accelerant poured
+/// on a corpse for 30 seconds.
+#[derive(Component, Debug)]
+pub struct DecayRateEffect {
+    /// The multiplier applied to the decay speed.
+    pub multiplier: f32,
+    /// The timer counting down the duration of the effect.
+    timer: Timer,
+}
+
+impl DecayRateEffect {
+    /// Creates a new `DecayRateEffect` scaling the decay speed for the given duration.
+    pub fn new(multiplier: f32, duration: Duration) -> Self {
+        Self {
+            multiplier,
+            timer: Timer::new(duration, TimerMode::Once),
+        }
+    }
+
+    /// Returns the remaining duration of the effect.
+    pub fn remaining(&self) -> Duration {
+        self.timer.remaining()
+    }
+}
+
+/// Set of keyed multipliers applied to the decay speed of an entity.
+///
+/// Independent systems (e.g. buffs) can insert and remove their own modifier by key without
+/// overwriting each other. The decay speed is scaled by the product of every modifier.
+#[derive(Component, Default, Clone, Debug)]
+pub struct DecayModifiers {
+    /// The multipliers, by key.
+    modifiers: BTreeMap<Cow<'static, str>, f32>,
+}
+
+impl DecayModifiers {
+    /// Inserts the modifier with the given key, returning the previous multiplier, if any.
+    pub fn insert(&mut self, key: impl Into<Cow<'static, str>>, multiplier: f32) -> Option<f32> {
+        self.modifiers.insert(key.into(), multiplier)
+    }
+
+    /// Removes the modifier with the given key, returning its multiplier, if any.
+    pub fn remove(&mut self, key: &str) -> Option<f32> {
+        self.modifiers.remove(key)
+    }
+
+    /// Returns the multiplier of the modifier with the given key, if any.
+    pub fn get(&self, key: &str) -> Option<f32> {
+        self.modifiers.get(key).copied()
+    }
+
+    /// Iterates over the modifiers as pairs of key and multiplier.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f32)> {
+        self.modifiers
+            .iter()
+            .map(|(key, multiplier)| (key.as_ref(), *multiplier))
+    }
+
+    /// Returns the product of every modifier.
+    pub fn multiplier(&self) -> f32 {
+        self.modifiers.values().product()
+    }
+}
+
+/// Registry of the systems contributing multipliers to the decay speed of entities, filled by
+/// [`DecayAppExt::register_decay_modifier`].
+///
+/// [`DecayAppExt::register_decay_modifier`]: crate::DecayAppExt::register_decay_modifier
+#[derive(Resource, Default)]
+pub(crate) struct DecayModifierRegistry {
+    /// The registered systems, keyed by their label.
+    pub(crate) modifiers: Vec<(Cow<'static, str>, SystemId<(), Vec<(Entity, f32)>>)>,
+}
+
+/// Piecewise multipliers applied to the decay speed of an entity over the elapsed fraction of
+/// its decay.
+///
+/// Each segment applies its multiplier until the elapsed fraction reaches its upper bound, and
+/// the speed is left unscaled beyond the last bound. E.g. `[(0.8, 0.5), (1.0, 5.0)]` decays
+/// slowly for the first 80% and five times as fast for the last 20%, modelling accelerating
+/// rot without extra stages. The multiplier is picked once per tick, from the fraction elapsed
+/// before it.
+#[derive(Component, Clone, Debug)]
+pub struct DecaySpeedSchedule {
+    /// The segments as pairs of upper bound and multiplier, by ascending bound.
+    segments: Vec<(f32, f32)>,
+}
+
+impl DecaySpeedSchedule {
+    /// Creates a new `DecaySpeedSchedule` from pairs of upper bound, from 0 to 1, and
+    /// multiplier.
+    ///
+    /// The segments are sorted by bound.
+    pub fn new(segments: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        let mut segments: Vec<_> = segments.into_iter().collect();
+        segments.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Self { segments }
+    }
+
+    /// Returns the segments as pairs of upper bound and multiplier.
+    pub fn segments(&self) -> &[(f32, f32)] {
+        &self.segments
+    }
+
+    /// Returns the multiplier applied at the given elapsed fraction.
+    pub fn multiplier(&self, fraction: f32) -> f32 {
+        self.segment(fraction)
+            .map_or(1.0, |(_, multiplier)| multiplier)
+    }
+
+    /// Returns the upper bound of the segment at the given elapsed fraction, if any.
+    pub fn next_bound(&self, fraction: f32) -> Option<f32> {
+        self.segment(fraction).map(|(bound, _)| bound)
+    }
+
+    /// Returns the segment at the given elapsed fraction, if any.
+    fn segment(&self, fraction: f32) -> Option<(f32, f32)> {
+        let index = self
+            .segments
+            .partition_point(|(bound, _)| *bound <= fraction);
+        self.segments.get(index).copied()
+    }
+}
+
+/// System that removes rate effects whose duration has elapsed.
+pub(crate) fn expire_rate_effects(
+    time: Res<Time>,
+    suspend: Option<Res<DecayFocusSuspend>>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut DecayRateEffect)>,
+) {
+    // While the app is unfocused, the effects are suspended along with the decays.
+    let Some(delta) = focus::decay_delta(&time, suspend.as_deref()) else {
+        return;
+    };
+
+    for (entity, mut effect) in query.iter_mut() {
+        // Progress the effect timer based on the time elapsed since the last frame.
+        effect.timer.tick(delta);
+
+        // If the effect is over, remove it from the entity.
+        if effect.timer.finished() {
+            commands.entity(entity).remove::<DecayRateEffect>();
+        }
+    }
+}
+
+/// System that runs every registered modifier, storing their multipliers in the
+/// `DecayModifiers` of the entities under the label of the modifier.
+///
+/// The key of a modifier is removed from the entities it no longer returns. It is only added
+/// by the first call to `register_decay_modifier`, so apps without modifiers do not run it.
+pub(crate) fn apply_registered_modifiers(world: &mut World) {
+    let Some(registry) = world.get_resource::<DecayModifierRegistry>() else {
+        return;
+    };
+
+    for (key, id) in registry.modifiers.clone() {
+        let multipliers: EntityHashMap<f32> = match world.run_system(id) {
+            Ok(multipliers) => multipliers.into_iter().collect(),
+            Err(error) => {
+                warn!("could not run the decay modifier {key}: {error}");
+                continue;
+            }
+        };
+
+        // Drop the multipliers the modifier no longer contributes.
+        let mut query = world.query::<(Entity, &mut DecayModifiers)>();
+        for (entity, mut modifiers) in query.iter_mut(world) {
+            if !multipliers.contains_key(&entity) && modifiers.get(&key).is_some() {
+                modifiers.remove(&key);
+            }
+        }
+
+        for (entity, multiplier) in multipliers {
+            let Ok(mut entity) = world.get_entity_mut(entity) else {
+                continue;
+            };
+
+            // Only touch the modifiers when the multiplier changed, keeping change detection.
+            match entity.get_mut::<DecayModifiers>() {
+                Some(modifiers) if modifiers.get(&key) == Some(multiplier) => {}
+                Some(mut modifiers) => {
+                    modifiers.insert(key.clone(), multiplier);
+                }
+                None => {
+                    let mut modifiers = DecayModifiers::default();
+                    modifiers.insert(key.clone(), multiplier);
+                    entity.insert(modifiers);
+                }
+            }
+        }
+    }
+}
+
+/// Query data combining everything that scales the decay speed of an entity.
+///
+/// Used by [`decaying`](crate::decaying) to scale the elapsed time of each decaying entity.
+#[derive(QueryData)]
+pub struct DecaySpeed {
+    /// The temporary rate effect of the entity.
+    rate_effect: Option<&'static DecayRateEffect>,
+    /// The combined multiplier of the regions the entity is inside of.
+    in_region: Option<&'static InDecayRegion>,
+    /// The stationary gate of the entity.
+    when_idle: Option<&'static DecayWhenIdle>,
+    /// The keyed modifiers of the entity.
+    modifiers: Option<&'static DecayModifiers>,
+    /// The piecewise multipliers of the entity.
+    schedule: Option<&'static DecaySpeedSchedule>,
+}
+
+impl DecaySpeedItem<'_> {
+    /// Returns the multiplier applied to the decay speed of the entity, regardless of the
+    /// elapsed fraction of its decay.
+    pub fn multiplier(&self) -> f32 {
+        // Entities that have not been stationary long enough do not decay.
+        if self.when_idle.is_some_and(|when_idle| !when_idle.is_idle()) {
+            return 0.0;
+        }
+
+        let rate_effect = self.rate_effect.map_or(1.0, |effect| effect.multiplier);
+        let in_region = self.in_region.map_or(1.0, |in_region| **in_region);
+        let modifiers = self.modifiers.map_or(1.0, DecayModifiers::multiplier);
+
+        (rate_effect * in_region * modifiers).max(0.0)
+    }
+
+    /// Returns the multiplier applied to the decay speed of the entity once the given fraction
+    /// of its decay has elapsed, including its `DecaySpeedSchedule`.
+    pub fn multiplier_at(&self, fraction: f32) -> f32 {
+        let schedule = self
+            .schedule
+            .map_or(1.0, |schedule| schedule.multiplier(fraction));
+
+        (self.multiplier() * schedule).max(0.0)
+    }
+
+    /// Returns the upper bound of the current segment of the `DecaySpeedSchedule` of the
+    /// entity at the given elapsed fraction, if any.
+    pub(crate) fn next_bound(&self, fraction: f32) -> Option<f32> {
+        self.schedule?.next_bound(fraction)
+    }
+}
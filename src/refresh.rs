@@ -0,0 +1,119 @@
+use crate::{DecayCompletionGuard, DecayDuration, DecayTimer};
+use bevy::{ecs::query::QueryFilter, prelude::*};
+use std::{marker::PhantomData, time::Duration};
+
+/// How the decay of an entity is refreshed by `RefreshDecayOnChange`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DecayRefresh {
+    /// The decay restarts with a freshly rolled duration from `DecayDuration`.
+    Restart,
+    /// The remaining time of the decay is increased by the given duration, up to the full
+    /// duration of the decay.
+    TopUp(Duration),
+    /// The decay continues with a freshly rolled duration from `DecayDuration`, keeping the
+    /// elapsed fraction.
+    ///
+    /// Paired with `RefreshDecayOnChange<DecayDuration>`, a decay whose range changes mid-way
+    /// (e.g. an item moved into a colder biome) is rescaled rather than restarted or left as is.
+    Rescale,
+}
+
+/// Refreshes the decay of an entity whenever its component `C` changes.
+///
+/// Covers keep-alive mechanics, like a campfire refuelled or a sign re-read, without bespoke
+/// observers. The refresh is handled by the `DecayPlugin` of every track registered with
+/// [`DecayPlugin::refresh_on_change`].
+///
+/// [`DecayPlugin::refresh_on_change`]: crate::DecayPlugin::refresh_on_change
+#[derive(Component)]
+pub struct RefreshDecayOnChange<C: Component> {
+    /// How the decay is refreshed.
+    pub refresh: DecayRefresh,
+    /// The component whose changes refresh the decay.
+    component: PhantomData<C>,
+}
+
+impl<C: Component> RefreshDecayOnChange<C> {
+    /// Creates a new `RefreshDecayOnChange` refreshing the decay as specified.
+    pub fn new(refresh: DecayRefresh) -> Self {
+        Self {
+            refresh,
+            component: PhantomData,
+        }
+    }
+}
+
+/// System that refreshes the decay of the track `T` for entities whose component `C` changed.
+pub(crate) fn refresh_decay_on_change<C: Component, T: Component>(
+    mut query: Query<
+        (
+            &mut DecayTimer<T>,
+            &RefreshDecayOnChange<C>,
+            Option<&DecayDuration>,
+            Ref<C>,
+        ),
+        Changed<C>,
+    >,
+) {
+    for (mut timer, refresh, decay_duration, component) in query.iter_mut() {
+        // Inserting the component is not a change, so a fresh decay keeps its start.
+        if component.is_added() {
+            continue;
+        }
+
+        match refresh.refresh {
+            // Restart with a re-rolled duration, or the previous one if it is not available.
+            DecayRefresh::Restart => {
+                let duration = decay_duration.map_or(timer.duration(), Duration::from);
+                timer.set_duration(duration);
+                timer.restart_at(Duration::ZERO);
+            }
+            // Re-roll the duration, keeping the elapsed fraction of the decay.
+            DecayRefresh::Rescale => {
+                let fraction = timer.fraction();
+                let duration = decay_duration.map_or(timer.duration(), Duration::from);
+                timer.set_duration(duration);
+                timer.restart_at(duration.mul_f32(fraction));
+            }
+            // Give back some time, restarting so a finished timer can run again.
+            DecayRefresh::TopUp(amount) => {
+                let elapsed = timer.elapsed().saturating_sub(amount);
+                timer.restart_at(elapsed);
+            }
+        }
+    }
+}
+
+/// Marker component re-rolling a fresh duration from `DecayDuration` whenever the decay of an
+/// entity resumes, instead of keeping the remaining time.
+///
+/// The timer is discarded when the track marker is removed, so the decay starts anew once the
+/// marker is inserted again. Use [`DecayPlugin::with_reroll_on_resume`] to re-roll the decay of
+/// every entity of a track.
+///
+/// [`DecayPlugin::with_reroll_on_resume`]: crate::DecayPlugin::with_reroll_on_resume
+#[derive(Component, Default, Debug)]
+pub struct RerollDecayOnResume;
+
+/// System that discards the timer of the track `T` when its marker is removed from an entity
+/// matching the filter `F`, so the decay is re-rolled once resumed.
+pub(crate) fn discard_decay_timer<T: Component, F: QueryFilter>(
+    trigger: Trigger<OnRemove, T>,
+    mut commands: Commands,
+    guard: Res<DecayCompletionGuard<T>>,
+    query: Query<(), (With<DecayTimer<T>>, F)>,
+) {
+    let entity = trigger.entity();
+
+    // The timer of completed decays is already removed along with the marker.
+    if guard.contains(entity) || !query.contains(entity) {
+        return;
+    }
+
+    // The entity may be despawning, in which case there is nothing left to discard.
+    commands.queue(move |world: &mut World| {
+        if let Ok(mut entity) = world.get_entity_mut(entity) {
+            entity.remove::<DecayTimer<T>>();
+        }
+    });
+}
@@ -0,0 +1,113 @@
+use crate::DecayDuration;
+use bevy::prelude::*;
+
+/// Region scaling the decay speed of the entities inside it.
+///
+/// The region is an axis-aligned box centered on the `GlobalTransform` of the region entity,
+/// extending by `half_extents` on each axis. Every decaying entity with a `GlobalTransform`
+/// inside the box has its decay speed scaled by the `multiplier`, so swamps can rot items
+/// faster and freezers slow them down. Overlapping regions multiply their multipliers.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Transform)]
+pub struct DecayRegion {
+    /// The multiplier applied to the decay speed of the entities inside the region.
+    pub multiplier: f32,
+    /// The half size of the region on each axis.
+    pub half_extents: Vec3,
+}
+
+impl DecayRegion {
+    /// Creates a new `DecayRegion` with the given multiplier and half extents.
+    pub fn new(multiplier: f32, half_extents: Vec3) -> Self {
+        Self {
+            multiplier,
+            half_extents,
+        }
+    }
+
+    /// Checks if the given point is inside the region centered on `center`.
+    pub fn contains(&self, center: Vec3, point: Vec3) -> bool {
+        (point - center).abs().cmple(self.half_extents).all()
+    }
+}
+
+/// Combined multiplier of the regions a decaying entity is inside of.
+#[derive(Component, Deref, Debug)]
+pub(crate) struct InDecayRegion(f32);
+
+/// Data of the decaying entities whose regions are evaluated.
+type RegionData = (
+    Entity,
+    &'static GlobalTransform,
+    Option<&'static mut InDecayRegion>,
+);
+
+/// Filter of the decaying entities which moved, or started to decay, since the last run.
+type MovedFilter = (
+    With<DecayDuration>,
+    Or<(Changed<GlobalTransform>, Added<DecayDuration>)>,
+);
+
+/// Filter of the regions which were added, changed or moved since the last run.
+type ChangedRegionFilter = (
+    With<DecayRegion>,
+    Or<(Changed<DecayRegion>, Changed<GlobalTransform>)>,
+);
+
+/// System that applies the multiplier of the regions to the decaying entities inside them.
+///
+/// Only the entities which moved are evaluated, unless a region was added, changed, moved or
+/// removed, in which case every decaying entity is.
+pub(crate) fn apply_decay_regions(
+    mut commands: Commands,
+    regions: Query<(&DecayRegion, &GlobalTransform)>,
+    changed_regions: Query<(), ChangedRegionFilter>,
+    mut removed_regions: RemovedComponents<DecayRegion>,
+    mut query: ParamSet<(
+        Query<RegionData, With<DecayDuration>>,
+        Query<RegionData, MovedFilter>,
+    )>,
+) {
+    let mut update =
+        |(entity, transform, in_region): (Entity, &GlobalTransform, Option<Mut<InDecayRegion>>)| {
+            let point = transform.translation();
+
+            // Combine the multipliers of every region containing the entity.
+            let multiplier = regions
+                .iter()
+                .filter(|(region, center)| region.contains(center.translation(), point))
+                .map(|(region, _)| region.multiplier)
+                .product::<f32>();
+
+            match in_region {
+                // Update the multiplier if the entity was already inside regions.
+                Some(mut in_region) if multiplier != 1.0 => in_region.0 = multiplier,
+                // Remove the multiplier if the entity left every region.
+                Some(_) => {
+                    commands.entity(entity).remove::<InDecayRegion>();
+                }
+                // Insert the multiplier if the entity entered a region.
+                None if multiplier != 1.0 => {
+                    commands.entity(entity).insert(InDecayRegion(multiplier));
+                }
+                None => {}
+            }
+        };
+
+    // Read every removal, so they are not seen again by the next run.
+    let removed = removed_regions.read().count() > 0;
+    if removed || !changed_regions.is_empty() {
+        query.p0().iter_mut().for_each(&mut update);
+    } else {
+        query.p1().iter_mut().for_each(&mut update);
+    }
+}
+
+/// Run condition checking if the regions must be evaluated, as long as there are regions or
+/// entities left inside one.
+pub(crate) fn any_decay_region(
+    regions: Query<(), With<DecayRegion>>,
+    in_region: Query<(), With<InDecayRegion>>,
+) -> bool {
+    !regions.is_empty() || !in_region.is_empty()
+}
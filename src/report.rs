@@ -0,0 +1,123 @@
+use crate::{Decay, DecayCompleted, DecayFrozen, DecayTimer, DurationFormat, NeverDecay};
+use bevy::prelude::*;
+use std::{any::type_name, marker::PhantomData, time::Duration};
+
+/// Plugin logging a compact report of the track `T` (`Decay` by default) every `interval`
+/// (a minute by default), making long-running headless servers observable without extra
+/// tooling.
+///
+/// The report lists the number of active and paused decays, the remaining time of the soonest
+/// expiry and the number of completions since the previous report. It is logged at the `info`
+/// level, measured in real time so it keeps coming while the virtual clock is paused. Use
+/// `DecayReportPlugin::default()` for the `Decay` track and `DecayReportPlugin::<T>::new()` for
+/// any other track.
+pub struct DecayReportPlugin<T: Component = Decay> {
+    /// The interval between reports.
+    pub interval: Duration,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+// `Default` is only implemented for the `Decay` track, so `DecayReportPlugin::default()` can
+// be inferred without naming the track.
+#[allow(clippy::new_without_default)]
+impl<T: Component> DecayReportPlugin<T> {
+    /// Creates a new `DecayReportPlugin` for the decay track of the marker component `T`,
+    /// reporting every minute.
+    pub fn new() -> Self {
+        Self {
+            interval: Duration::from_secs(60),
+            track: PhantomData,
+        }
+    }
+
+    /// Reports every `interval` instead of every minute.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+}
+
+impl Default for DecayReportPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Component> Plugin for DecayReportPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(DecayReport::<T>::new(self.interval))
+            .add_observer(count_completions::<T>)
+            .add_systems(Last, log_decay_report::<T>);
+    }
+}
+
+/// State of the periodic report of the track `T`.
+#[derive(Resource)]
+struct DecayReport<T: Component> {
+    /// The timer counting down the interval between reports.
+    timer: Timer,
+    /// The completions since the previous report.
+    completions: usize,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayReport<T> {
+    /// Creates a new `DecayReport` reporting every `interval`.
+    fn new(interval: Duration) -> Self {
+        Self {
+            timer: Timer::new(interval, TimerMode::Repeating),
+            completions: 0,
+            track: PhantomData,
+        }
+    }
+}
+
+/// System that counts the completions of the track `T` for the next report.
+fn count_completions<T: Component>(
+    trigger: Trigger<DecayCompleted<T>>,
+    mut report: ResMut<DecayReport<T>>,
+) {
+    report.completions += trigger.len();
+}
+
+/// System that logs the report of the track `T` once the interval elapsed.
+fn log_decay_report<T: Component>(
+    time: Res<Time<Real>>,
+    mut report: ResMut<DecayReport<T>>,
+    query: Query<(&DecayTimer<T>, Has<T>, Has<DecayFrozen>), Without<NeverDecay>>,
+) {
+    report.timer.tick(time.delta());
+    if !report.timer.just_finished() {
+        return;
+    }
+
+    let mut active = 0;
+    let mut paused = 0;
+    let mut soonest: Option<Duration> = None;
+
+    for (timer, marker, frozen) in query.iter() {
+        if !marker || frozen || timer.paused() {
+            paused += 1;
+            continue;
+        }
+
+        active += 1;
+        let remaining = timer.remaining();
+        soonest = Some(soonest.map_or(remaining, |soonest| soonest.min(remaining)));
+    }
+
+    let soonest = soonest.map_or_else(
+        || "-".to_string(),
+        |soonest| DurationFormat::default().format(soonest),
+    );
+    let completions = std::mem::take(&mut report.completions);
+
+    let header = format!(
+        "{:>8} {:>8} {:>10} {:>10}",
+        "active", "paused", "soonest", "completed"
+    );
+    let row = format!("{active:>8} {paused:>8} {soonest:>10} {completions:>10}");
+    info!("decay report of {}\n  {header}\n  {row}", type_name::<T>());
+}
@@ -0,0 +1,193 @@
+use crate::{
+    focus, DecayCorePlugin, DecayDuration, DecayFocusSuspend, DecayPauseSources, DecayingSet,
+};
+use bevy::prelude::*;
+use std::{borrow::Cow, marker::PhantomData, time::Duration};
+
+/// Plugin counting down the `DecayingResource<R>` of the standalone resource `R`.
+///
+/// Covers values living outside of entities, like a global event timer or the intensity of the
+/// weather, with the same lifecycle as decaying entities: `ResourceDecayStarted`,
+/// `ResourceDecayPaused` and `ResourceDecayCompleted` events, and pauses counted by source.
+pub struct DecayResourcePlugin<R: Resource> {
+    /// The decaying resource.
+    resource: PhantomData<R>,
+}
+
+// `Default` is not implemented, as there is no default resource to infer.
+#[allow(clippy::new_without_default)]
+impl<R: Resource> DecayResourcePlugin<R> {
+    /// Creates a new `DecayResourcePlugin` counting down the resource `R`.
+    pub fn new() -> Self {
+        Self {
+            resource: PhantomData,
+        }
+    }
+}
+
+impl<R: Resource> Plugin for DecayResourcePlugin<R> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<DecayCorePlugin>() {
+            app.add_plugins(DecayCorePlugin);
+        }
+
+        app.add_event::<ResourceDecayStarted<R>>()
+            .add_event::<ResourceDecayPaused<R>>()
+            .add_event::<ResourceDecayCompleted<R>>()
+            .add_systems(
+                PreUpdate,
+                decaying_resource::<R>
+                    .run_if(resource_exists::<DecayingResource<R>>)
+                    .in_set(DecayingSet::Tick),
+            );
+    }
+}
+
+/// Countdown of the standalone resource `R`, handled by the `DecayResourcePlugin<R>`.
+///
+/// The decay starts once this resource is inserted, and completes once its duration has
+/// elapsed, removing itself along with `R` when `remove_on_complete` is set. Like the decay of
+/// entities, it is paused for as long as any source holds a pause.
+#[derive(Resource, Debug)]
+pub struct DecayingResource<R: Resource> {
+    /// Whether the resource `R` is removed once the decay completes.
+    pub remove_on_complete: bool,
+    /// The timer of the decay.
+    timer: Timer,
+    /// The sources pausing the decay.
+    sources: DecayPauseSources,
+    /// Whether the decay was paused during the last frame, or `None` before it started.
+    was_paused: Option<bool>,
+    /// The decaying resource.
+    resource: PhantomData<R>,
+}
+
+impl<R: Resource> DecayingResource<R> {
+    /// Creates a new `DecayingResource` with a duration rolled from the given range.
+    pub fn new(duration: &DecayDuration) -> Self {
+        Self {
+            remove_on_complete: false,
+            timer: Timer::new(Duration::from(duration), TimerMode::Once),
+            sources: DecayPauseSources::default(),
+            was_paused: None,
+            resource: PhantomData,
+        }
+    }
+
+    /// Removes the resource `R` once the decay completes.
+    pub fn removing(mut self) -> Self {
+        self.remove_on_complete = true;
+        self
+    }
+
+    /// Returns the total duration of the decay.
+    pub fn duration(&self) -> Duration {
+        self.timer.duration()
+    }
+
+    /// Returns the time elapsed since the decay started.
+    pub fn elapsed(&self) -> Duration {
+        self.timer.elapsed()
+    }
+
+    /// Returns the remaining duration of the decay.
+    pub fn remaining(&self) -> Duration {
+        self.timer.remaining()
+    }
+
+    /// Returns the fraction of the decay that has elapsed, from 0 to 1.
+    pub fn fraction(&self) -> f32 {
+        self.timer.fraction()
+    }
+
+    /// Checks if the decay is paused.
+    pub fn paused(&self) -> bool {
+        !self.sources.is_empty()
+    }
+
+    /// Returns the sources pausing the decay.
+    pub fn sources(&self) -> &DecayPauseSources {
+        &self.sources
+    }
+
+    /// Adds a pause of the given source, pausing the decay.
+    pub fn pause(&mut self, source: impl Into<Cow<'static, str>>) {
+        self.sources.add(source.into());
+    }
+
+    /// Releases a pause of the given source, resuming the decay once no source is left.
+    pub fn resume(&mut self, source: &str) {
+        self.sources.release(source);
+    }
+}
+
+/// Event triggered when the decay of the resource `R` starts or resumes.
+#[derive(Event, Debug)]
+pub struct ResourceDecayStarted<R: Resource> {
+    /// The remaining duration of the decay.
+    pub duration: Duration,
+    /// Whether the decay resumed after being paused, rather than starting anew.
+    pub resumed: bool,
+    /// The decaying resource.
+    resource: PhantomData<R>,
+}
+
+/// Event triggered when the decay of the resource `R` is paused.
+#[derive(Event, Debug)]
+pub struct ResourceDecayPaused<R: Resource> {
+    /// The remaining duration of decay when the process was paused.
+    pub remaining_duration: Duration,
+    /// The fraction of the decay that had elapsed when the process was paused, from 0 to 1.
+    pub fraction_elapsed: f32,
+    /// The decaying resource.
+    resource: PhantomData<R>,
+}
+
+/// Event triggered when the decay of the resource `R` completes.
+#[derive(Event, Debug)]
+pub struct ResourceDecayCompleted<R: Resource>(PhantomData<R>);
+
+/// System that counts down the decay of the resource `R`.
+fn decaying_resource<R: Resource>(
+    time: Res<Time>,
+    suspend: Option<Res<DecayFocusSuspend>>,
+    mut commands: Commands,
+    mut decaying: ResMut<DecayingResource<R>>,
+) {
+    let paused = decaying.paused();
+
+    // Trigger the lifecycle events when the decay starts, is paused or resumes.
+    match (decaying.was_paused, paused) {
+        (None, false) | (Some(true), false) => commands.trigger(ResourceDecayStarted::<R> {
+            duration: decaying.remaining(),
+            resumed: decaying.was_paused.is_some(),
+            resource: PhantomData,
+        }),
+        (Some(false), true) => commands.trigger(ResourceDecayPaused::<R> {
+            remaining_duration: decaying.remaining(),
+            fraction_elapsed: decaying.fraction(),
+            resource: PhantomData,
+        }),
+        _ => {}
+    }
+    decaying.was_paused = Some(paused);
+
+    if paused {
+        return;
+    }
+
+    // While the app is unfocused, the decay is suspended along with the decays of entities.
+    let Some(delta) = focus::decay_delta(&time, suspend.as_deref()) else {
+        return;
+    };
+    decaying.timer.tick(delta);
+
+    if decaying.timer.finished() {
+        commands.remove_resource::<DecayingResource<R>>();
+        if decaying.remove_on_complete {
+            commands.remove_resource::<R>();
+        }
+
+        commands.trigger(ResourceDecayCompleted::<R>(PhantomData));
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{Decay, DecayDuration, DecayTimer};
+use bevy::{ecs::world::Command, prelude::*};
+use std::{marker::PhantomData, time::Duration};
+
+/// The exact state of the decay of an entity, independent of the entity and of the world.
+///
+/// Lets a decaying entity be persisted through a `DecayStore`, or migrated between the worlds
+/// or processes of a sharded server with [`extract_decay_state`] and [`apply_decay_state`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecayState {
+    /// The time elapsed since the decay started.
+    pub elapsed: Duration,
+    /// The total duration of the decay.
+    pub duration: Duration,
+    /// Whether the decay is paused.
+    pub paused: bool,
+}
+
+impl DecayState {
+    /// Returns the state of the decay with the given timer.
+    pub fn of<T: Component>(timer: &DecayTimer<T>) -> Self {
+        Self {
+            elapsed: timer.elapsed(),
+            duration: timer.duration(),
+            paused: timer.paused(),
+        }
+    }
+
+    /// Returns the time left before the decay completes.
+    pub fn remaining(&self) -> Duration {
+        self.duration.saturating_sub(self.elapsed)
+    }
+}
+
+/// Extracts the state of the decay of the track `T` of an entity, if it has one.
+///
+/// Together with [`apply_decay_state`], it hands the authority over a decaying entity to
+/// another world or process, keeping its exact remaining time and pause status.
+pub fn extract_decay_state<T: Component>(world: &World, entity: Entity) -> Option<DecayState> {
+    world.get::<DecayTimer<T>>(entity).map(DecayState::of)
+}
+
+/// Applies an extracted decay state of the track `T` to an entity, such as the copy of a
+/// migrated entity.
+///
+/// The entity gets a timer with the exact elapsed time of the state. A running decay also gets
+/// the track marker, so the `DecayStarted` event is triggered with `resumed` set, while a
+/// paused decay resumes once the marker is inserted again. Missing entities are skipped.
+pub fn apply_decay_state<T: Component + Default>(
+    world: &mut World,
+    entity: Entity,
+    state: DecayState,
+) {
+    let Ok(mut entity) = world.get_entity_mut(entity) else {
+        return;
+    };
+
+    // Keep the duration range of the entity, if any, so stacks can still re-roll it.
+    if !entity.contains::<DecayDuration>() {
+        entity.insert(DecayDuration::new(state.duration));
+    }
+
+    let mut timer = DecayTimer::<T>::new(state.duration);
+    timer.restart_at(state.elapsed);
+
+    if state.paused {
+        timer.pause();
+        entity.insert(timer);
+    } else {
+        // The marker is inserted alongside the timer, so the decay is resumed.
+        entity.insert((timer, T::default()));
+    }
+}
+
+/// A persisted decay to restore, such as one loaded from a `DecayStore` at startup.
+#[derive(Clone, Copy, Debug)]
+pub struct DecayRecord {
+    /// The entity to restore the decay on, mapped from the key the decay was persisted with.
+    pub entity: Entity,
+    /// The time left before the decay completes.
+    pub remaining: Duration,
+    /// Whether the decay was paused.
+    pub paused: bool,
+}
+
+/// Command restoring persisted decays of the track `T` (`Decay` by default).
+///
+/// Persisted keys must first be mapped to entities, e.g. by spawning the persisted items. Each
+/// running decay is restored with a timer of its remaining duration and the track marker, so
+/// the `DecayStarted` event is triggered with `resumed` set and gameplay reacts as for any
+/// other resumed decay. Paused decays only get a paused timer, resuming once the marker is
+/// inserted again. Records of missing entities are skipped.
+///
+/// Queue it with `commands.queue(RestoreDecays::<Decay>::new(records))`.
+pub struct RestoreDecays<T: Component = Decay> {
+    /// The decays to restore.
+    records: Vec<DecayRecord>,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> RestoreDecays<T> {
+    /// Creates a new `RestoreDecays` command restoring the given records.
+    pub fn new(records: Vec<DecayRecord>) -> Self {
+        Self {
+            records,
+            track: PhantomData,
+        }
+    }
+}
+
+impl<T: Component + Default> Command for RestoreDecays<T> {
+    fn apply(self, world: &mut World) {
+        for record in self.records {
+            let Ok(mut entity) = world.get_entity_mut(record.entity) else {
+                continue;
+            };
+
+            // Keep the duration range of the entity, if any, so stacks can still re-roll it.
+            if !entity.contains::<DecayDuration>() {
+                entity.insert(DecayDuration::new(record.remaining));
+            }
+
+            let mut timer = DecayTimer::<T>::new(record.remaining);
+            if record.paused {
+                timer.pause();
+                entity.insert(timer);
+            } else {
+                // The marker is inserted alongside the timer, so the decay is resumed.
+                entity.insert((timer, T::default()));
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{Decay, DecayCharges, DecayTimer, NeverDecay};
+use bevy::{
+    prelude::*,
+    tasks::{block_on, AsyncComputeTaskPool, Task},
+};
+use std::{marker::PhantomData, time::Duration};
+
+/// Resource scheduling the upcoming completions of the track `T` from a background scan.
+///
+/// Every scan interval, the remaining time of the running decays is snapshotted and handed to
+/// an `AsyncComputeTaskPool` task sorting them into deadlines, so the main thread only checks
+/// the entities whose deadline has passed instead of every decaying entity.
+///
+/// It is managed by the plugin, and only public so [`complete_decay`](crate::complete_decay)
+/// can be scheduled manually.
+#[derive(Resource)]
+pub struct DecayScan<T: Component = Decay> {
+    /// The timer counting down the interval between scans.
+    interval: Timer,
+    /// The scan in progress, if any.
+    task: Option<Task<Vec<(Duration, Entity)>>>,
+    /// The upcoming deadlines, sorted from the latest to the soonest.
+    schedule: Vec<(Duration, Entity)>,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayScan<T> {
+    /// Creates a new `DecayScan` scanning the running decays every `interval`.
+    pub(crate) fn new(interval: Duration) -> Self {
+        Self {
+            interval: Timer::new(interval, TimerMode::Repeating),
+            task: None,
+            schedule: Vec::new(),
+            track: PhantomData,
+        }
+    }
+
+    /// Defers the given entities to the next frame, checking them again first.
+    pub(crate) fn defer(&mut self, entities: Vec<Entity>) {
+        self.schedule.extend(
+            entities
+                .into_iter()
+                .rev()
+                .map(|entity| (Duration::ZERO, entity)),
+        );
+    }
+
+    /// Replaces the schedule with the given deadlines. The entries already due are kept, as they
+    /// were deferred by a budget or a barrier after the deadlines were snapshotted.
+    fn merge(&mut self, mut schedule: Vec<(Duration, Entity)>, now: Duration) {
+        schedule.extend(
+            self.schedule
+                .iter()
+                .filter(|(deadline, _)| *deadline <= now),
+        );
+        schedule.sort_unstable_by(|a, b| b.cmp(a));
+        self.schedule = schedule;
+    }
+
+    /// Takes the entities whose deadline is at or before `now`.
+    pub(crate) fn take_ready(&mut self, now: Duration) -> Vec<Entity> {
+        let ready = self
+            .schedule
+            .iter()
+            .rev()
+            .take_while(|(deadline, _)| *deadline <= now)
+            .count();
+
+        self.schedule
+            .drain(self.schedule.len() - ready..)
+            .map(|(_, entity)| entity)
+            .collect()
+    }
+}
+
+/// Query of the decays whose deadlines are scanned on the track `T`.
+type ScanQuery<'w, 's, T> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static DecayTimer<T>,
+        Option<&'static DecayCharges>,
+    ),
+    (With<T>, Without<NeverDecay>),
+>;
+
+/// System that applies the finished scan and starts the next one once the interval elapsed.
+pub(crate) fn scan_decay_deadlines<T: Component>(
+    time: Res<Time>,
+    mut scan: ResMut<DecayScan<T>>,
+    query: ScanQuery<T>,
+) {
+    let now = time.elapsed();
+
+    // Merge the scan in progress into the schedule once it is finished.
+    if scan.task.as_ref().is_some_and(Task::is_finished) {
+        if let Some(task) = scan.task.take() {
+            scan.merge(block_on(task), now);
+        }
+    }
+
+    scan.interval.tick(time.delta());
+    if !scan.interval.finished() || scan.task.is_some() {
+        return;
+    }
+
+    // Sort the deadlines in the background, from the latest to the soonest.
+    let mut deadlines = deadlines(now, &query);
+    scan.task = Some(AsyncComputeTaskPool::get().spawn(async move {
+        deadlines.sort_unstable_by(|a, b| b.cmp(a));
+        deadlines
+    }));
+}
+
+/// System that scans the deadlines of the track `T` at once, replacing the scan in progress.
+///
+/// Used by simulations, whose steps span many scan intervals, so every decay completes at the
+/// end of the step it finishes in.
+pub(crate) fn rescan_decay_deadlines<T: Component>(
+    time: Res<Time>,
+    mut scan: ResMut<DecayScan<T>>,
+    query: ScanQuery<T>,
+) {
+    let now = time.elapsed();
+    scan.task = None;
+    scan.merge(deadlines(now, &query), now);
+}
+
+/// Snapshots the deadlines of the running decays, exhausted charges completing right away.
+fn deadlines<T: Component>(now: Duration, query: &ScanQuery<T>) -> Vec<(Duration, Entity)> {
+    query
+        .iter()
+        .filter(|(_, timer, _)| !timer.paused())
+        .map(|(entity, timer, charges)| {
+            let remaining = if charges.is_some_and(|charges| charges.0 == 0) {
+                Duration::ZERO
+            } else {
+                timer.remaining()
+            };
+            (now + remaining, entity)
+        })
+        .collect()
+}
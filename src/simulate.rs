@@ -0,0 +1,125 @@
+use crate::{
+    lane, lod, step, DecayDelayTimer, DecayDisabled, DecayFrozen, DecayLodWaiting,
+    DecayLongLaneMember, DecaySpeed, DecayTimer, NeverDecay,
+};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// The shortest step of a simulation, so float rounding cannot stall it.
+const MIN_STEP: Duration = Duration::from_millis(1);
+
+/// Resource listing the decay tracks registered by each `DecayPlugin`, for simulations.
+#[derive(Resource, Default)]
+pub(crate) struct DecayTracks(Vec<DecayTrack>);
+
+impl DecayTracks {
+    /// Registers the track `T`.
+    pub(crate) fn register<T: Component>(&mut self) {
+        self.0.push(DecayTrack {
+            next_deadline: next_deadline::<T>,
+            run: step::run_track::<T>,
+        });
+    }
+}
+
+/// Functions simulating a decay track.
+#[derive(Clone, Copy)]
+struct DecayTrack {
+    /// Returns the time until the next decay or delay of the track finishes, if any.
+    next_deadline: fn(&mut World) -> Option<Duration>,
+    /// Runs the decay systems of the track once.
+    run: fn(&mut World),
+}
+
+/// Simulates the decay of every registered track over the given span in one call, such as
+/// to catch the world up after server downtime.
+///
+/// The world is stepped from one finishing decay to the next, so completions, chained stages
+/// and their events are processed in chronological order, as they would have over the span.
+/// Decays started by completions (e.g. chained tracks) are simulated as well.
+pub fn simulate(world: &mut World, span: Duration) {
+    let tracks = world
+        .get_resource::<DecayTracks>()
+        .map(|tracks| tracks.0.clone())
+        .unwrap_or_default();
+
+    let mut left = span;
+    while !left.is_zero() {
+        // Step to the next finishing decay of any track, or to the end of the span.
+        let next = tracks
+            .iter()
+            .filter_map(|track| (track.next_deadline)(world))
+            .min();
+        let dt = next.map_or(left, |next| next.max(MIN_STEP)).min(left);
+
+        step::advance_time(world, dt);
+        step::run_core(world);
+        for track in &tracks {
+            (track.run)(world);
+        }
+
+        left -= dt;
+    }
+}
+
+/// Query filter for the decays ticked every frame on the track `T`, as by
+/// [`decaying`](crate::decaying).
+type DecayFilter<T> = (
+    With<T>,
+    Without<DecayFrozen>,
+    Without<NeverDecay>,
+    Without<DecayLongLaneMember<T>>,
+    Without<DecayLodWaiting<T>>,
+    Without<DecayDisabled<T>>,
+);
+
+/// Query filter for the delays counted down on the track `T`.
+type DelayFilter<T> = (
+    With<T>,
+    Without<DecayFrozen>,
+    Without<NeverDecay>,
+    Without<DecayDisabled<T>>,
+);
+
+/// Returns the time until the next decay or delay of the track `T` finishes, the next member of
+/// its long lane returns to the precise lane, or the next entity waiting for its `DecayLod` is
+/// ticked, if any.
+fn next_deadline<T: Component>(world: &mut World) -> Option<Duration> {
+    let mut timers = world.query_filtered::<(&DecayTimer<T>, DecaySpeed), DecayFilter<T>>();
+    let decays = timers
+        .iter(world)
+        // Finished decays either completed during the last step or wait for their group.
+        .filter(|(timer, _)| !timer.paused() && !timer.finished())
+        .filter_map(|(timer, speed)| {
+            // Decays that do not progress never finish.
+            let fraction = timer.fraction();
+            let multiplier = speed.multiplier_at(fraction);
+            if multiplier <= 0.0 {
+                return None;
+            }
+
+            // With a speed schedule, stop at the end of the current segment as well.
+            let remaining = match speed.next_bound(fraction) {
+                Some(bound) => timer
+                    .remaining()
+                    .min(timer.duration().mul_f32(bound - fraction)),
+                None => timer.remaining(),
+            };
+
+            Some(remaining.div_f32(multiplier))
+        })
+        .min();
+
+    let mut delays = world.query_filtered::<&DecayDelayTimer<T>, DelayFilter<T>>();
+    let delays = delays.iter(world).map(|delay| delay.remaining()).min();
+
+    let lane = lane::next_check::<T>(world);
+    let lod = lod::next_tick::<T>(world);
+
+    decays
+        .into_iter()
+        .chain(delays)
+        .chain(lane)
+        .chain(lod)
+        .min()
+}
@@ -0,0 +1,328 @@
+use crate::DecayState;
+#[cfg(feature = "ron")]
+use crate::{apply_decay_state, DecayGroup, DecayTimer};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fmt};
+
+/// The version of the `DecaySnapshot` format written by this version of the crate.
+pub const DECAY_SNAPSHOT_VERSION: u32 = 1;
+
+/// Versioned, serializable snapshot of decay states, such as the ones persisted by a server.
+///
+/// The snapshot records the version of the format it was written with, so a server upgrading
+/// the crate can still load older snapshots: fields added by later versions default when
+/// missing, and [`from_ron`](Self::from_ron) brings the document up to date with the
+/// `DecaySnapshotMigrations` registered for each older version before deserializing it,
+/// instead of resetting every decay.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct DecaySnapshot {
+    /// The version of the format the snapshot was written with.
+    pub version: u32,
+    /// The persisted decays.
+    #[serde(default)]
+    pub decays: Vec<DecaySnapshotEntry>,
+}
+
+impl DecaySnapshot {
+    /// Creates a new `DecaySnapshot` of the given decays, with the current version.
+    pub fn new(decays: Vec<DecaySnapshotEntry>) -> Self {
+        Self {
+            version: DECAY_SNAPSHOT_VERSION,
+            decays,
+        }
+    }
+
+    /// Checks if the snapshot was written with the current version of the format.
+    pub fn is_current(&self) -> bool {
+        self.version == DECAY_SNAPSHOT_VERSION
+    }
+
+    /// Deserializes a snapshot from a RON document, migrating it to the current version
+    /// first.
+    ///
+    /// The document is parsed into an untyped value, its version is read and the migration of
+    /// each older version is applied in turn, so the migrations see the layout of the version
+    /// they migrate from. Only then is the value deserialized into a `DecaySnapshot`.
+    #[cfg(feature = "ron")]
+    pub fn from_ron(
+        document: &str,
+        migrations: &DecaySnapshotMigrations,
+    ) -> Result<Self, DecaySnapshotRonError> {
+        let mut value: ron::Value = ron::from_str(document)?;
+        migrations.migrate(&mut value)?;
+        value
+            .into_rust()
+            .map_err(DecaySnapshotRonError::Deserialize)
+    }
+}
+
+impl Default for DecaySnapshot {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+/// Decay of a single entity in a `DecaySnapshot`.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub struct DecaySnapshotEntry {
+    /// The durable key of the decaying entity, such as the bits of the entity or the
+    /// identifier of the persisted item.
+    pub key: u64,
+    /// The state of the decay, holding its remaining time and pause status.
+    pub state: DecayState,
+    /// The durable key of the `DecayGroup` of the entity, if any.
+    #[serde(default)]
+    pub group: Option<u64>,
+}
+
+/// Migrations bringing the RON document of a `DecaySnapshot` from each older version of the
+/// format to the next one.
+///
+/// Each migration receives the untyped value of the document in the layout of the version it
+/// migrates from, and fixes it up into the layout of the next one (e.g. renaming fields,
+/// converting units or filling new fields); the version itself is bumped afterwards. Insert it
+/// as a resource so the loading code can find it.
+#[cfg(feature = "ron")]
+#[derive(Resource, Default, Clone)]
+pub struct DecaySnapshotMigrations {
+    /// The migrations, indexed by the version they migrate from.
+    migrations: Vec<(u32, fn(&mut ron::Value))>,
+}
+
+#[cfg(feature = "ron")]
+impl DecaySnapshotMigrations {
+    /// Registers the migration from the given version to the next one, replacing any previous
+    /// migration from the same version.
+    pub fn with_migration(mut self, from: u32, migration: fn(&mut ron::Value)) -> Self {
+        self.migrations.retain(|(version, _)| *version != from);
+        self.migrations.push((from, migration));
+        self
+    }
+
+    /// Returns the migration from the given version, if registered.
+    pub fn get(&self, from: u32) -> Option<fn(&mut ron::Value)> {
+        self.migrations
+            .iter()
+            .find(|(version, _)| *version == from)
+            .map(|(_, migration)| *migration)
+    }
+
+    /// Brings the untyped value of a snapshot up to the current version, applying the
+    /// migration of each older version in turn.
+    ///
+    /// Fails if the value has no version, if it was written by a newer version of the crate,
+    /// or if a migration is missing along the way.
+    pub fn migrate(&self, value: &mut ron::Value) -> Result<(), DecaySnapshotError> {
+        let mut version = snapshot_version(value).ok_or(DecaySnapshotError::MissingVersion)?;
+        if version > DECAY_SNAPSHOT_VERSION {
+            return Err(DecaySnapshotError::Newer(version));
+        }
+
+        while version < DECAY_SNAPSHOT_VERSION {
+            let migration = self
+                .get(version)
+                .ok_or(DecaySnapshotError::MissingMigration(version))?;
+
+            migration(value);
+            version += 1;
+            set_snapshot_version(value, version);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the key of the version field of a snapshot.
+#[cfg(feature = "ron")]
+fn version_key() -> ron::Value {
+    ron::Value::String("version".to_string())
+}
+
+/// Returns the version of the untyped value of a snapshot, if any.
+#[cfg(feature = "ron")]
+fn snapshot_version(value: &ron::Value) -> Option<u32> {
+    let ron::Value::Map(map) = value else {
+        return None;
+    };
+    let ron::Value::Number(version) = map.get(&version_key())? else {
+        return None;
+    };
+
+    version
+        .as_i64()
+        .and_then(|version| u32::try_from(version).ok())
+}
+
+/// Sets the version of the untyped value of a snapshot.
+#[cfg(feature = "ron")]
+fn set_snapshot_version(value: &mut ron::Value, version: u32) {
+    if let ron::Value::Map(map) = value {
+        map.insert(
+            version_key(),
+            ron::Value::Number(ron::Number::from(i64::from(version))),
+        );
+    }
+}
+
+/// Error raised while migrating a `DecaySnapshot`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DecaySnapshotError {
+    /// The snapshot has no version.
+    MissingVersion,
+    /// The snapshot was written by a newer version of the crate.
+    Newer(u32),
+    /// No migration is registered from the given version.
+    MissingMigration(u32),
+}
+
+impl fmt::Display for DecaySnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingVersion => write!(f, "the decay snapshot has no version"),
+            Self::Newer(version) => write!(
+                f,
+                "the decay snapshot version {version} is newer than {DECAY_SNAPSHOT_VERSION}"
+            ),
+            Self::MissingMigration(version) => write!(
+                f,
+                "no decay snapshot migration is registered from version {version}"
+            ),
+        }
+    }
+}
+
+impl Error for DecaySnapshotError {}
+
+/// Event triggered by [`DecayCommandsExt::export_decay_snapshot`] with the exported RON
+/// document.
+///
+/// [`DecayCommandsExt::export_decay_snapshot`]: crate::DecayCommandsExt::export_decay_snapshot
+#[cfg(feature = "ron")]
+#[derive(Event, Clone, Debug)]
+pub struct DecaySnapshotExported {
+    /// The RON document of the `DecaySnapshot`.
+    pub document: String,
+}
+
+/// Exports every active or paused decay of the track `T` into the RON document of a
+/// `DecaySnapshot`, for backups, debugging or transferring worlds between environments.
+///
+/// Entities are keyed by their bits, as are their `DecayGroup`.
+#[cfg(feature = "ron")]
+pub fn export_decay_snapshot<T: Component>(
+    world: &mut World,
+) -> Result<String, DecaySnapshotRonError> {
+    // Paused decays are exported too, as they keep their timer without the marker.
+    let mut query = world.query::<(Entity, &DecayTimer<T>, Option<&DecayGroup>)>();
+
+    let mut decays: Vec<_> = query
+        .iter(world)
+        .map(|(entity, timer, group)| DecaySnapshotEntry {
+            key: entity.to_bits(),
+            state: DecayState::of(timer),
+            group: group.map(|group| group.0.to_bits()),
+        })
+        .collect();
+
+    // Keep the document stable across exports of the same world.
+    decays.sort_by_key(|entry| entry.key);
+
+    let snapshot = DecaySnapshot::new(decays);
+    Ok(ron::ser::to_string_pretty(
+        &snapshot,
+        ron::ser::PrettyConfig::default(),
+    )?)
+}
+
+/// Imports the decays of the track `T` from the RON document of a `DecaySnapshot`, returning
+/// the number of decays applied.
+///
+/// Older snapshots are migrated with the `DecaySnapshotMigrations` resource, if any. Each decay
+/// is applied as by [`apply_decay_state`] to the entity with the bits of its key, along with
+/// its `DecayGroup`, and decays of missing entities are skipped. Deserialize the
+/// [`DecaySnapshot`] with [`DecaySnapshot::from_ron`] to map the keys to other entities instead.
+#[cfg(feature = "ron")]
+pub fn import_decay_snapshot<T: Component + Default>(
+    world: &mut World,
+    document: &str,
+) -> Result<usize, DecaySnapshotRonError> {
+    let snapshot = match world.get_resource::<DecaySnapshotMigrations>() {
+        Some(migrations) => DecaySnapshot::from_ron(document, migrations)?,
+        None => DecaySnapshot::from_ron(document, &DecaySnapshotMigrations::default())?,
+    };
+
+    let mut applied = 0;
+    for entry in snapshot.decays {
+        let Some(entity) = Entity::try_from_bits(entry.key)
+            .ok()
+            .filter(|&entity| world.get_entity(entity).is_ok())
+        else {
+            continue;
+        };
+
+        if let Some(group) = entry
+            .group
+            .and_then(|group| Entity::try_from_bits(group).ok())
+        {
+            world.entity_mut(entity).insert(DecayGroup(group));
+        }
+
+        apply_decay_state::<T>(world, entity, entry.state);
+        applied += 1;
+    }
+
+    Ok(applied)
+}
+
+/// Error raised while exporting or importing the RON document of a `DecaySnapshot`.
+#[cfg(feature = "ron")]
+#[derive(Debug)]
+pub enum DecaySnapshotRonError {
+    /// The snapshot could not be serialized.
+    Serialize(ron::Error),
+    /// The document is not valid RON.
+    Parse(ron::error::SpannedError),
+    /// The migrated document is not a valid snapshot.
+    Deserialize(ron::Error),
+    /// The snapshot could not be migrated to the current version.
+    Migrate(DecaySnapshotError),
+}
+
+#[cfg(feature = "ron")]
+impl fmt::Display for DecaySnapshotRonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Serialize(error) => write!(f, "could not serialize the decay snapshot: {error}"),
+            Self::Parse(error) => write!(f, "could not parse the decay snapshot: {error}"),
+            Self::Deserialize(error) => {
+                write!(f, "could not deserialize the decay snapshot: {error}")
+            }
+            Self::Migrate(error) => write!(f, "could not migrate the decay snapshot: {error}"),
+        }
+    }
+}
+
+#[cfg(feature = "ron")]
+impl Error for DecaySnapshotRonError {}
+
+#[cfg(feature = "ron")]
+impl From<ron::Error> for DecaySnapshotRonError {
+    fn from(error: ron::Error) -> Self {
+        Self::Serialize(error)
+    }
+}
+
+#[cfg(feature = "ron")]
+impl From<ron::error::SpannedError> for DecaySnapshotRonError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+#[cfg(feature = "ron")]
+impl From<DecaySnapshotError> for DecaySnapshotRonError {
+    fn from(error: DecaySnapshotError) -> Self {
+        Self::Migrate(error)
+    }
+}
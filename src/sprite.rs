@@ -0,0 +1,99 @@
+use crate::{Decay, DecayTimer};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Appearance of a decaying sprite during a stage of a `DecayStageSprites`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum DecaySprite {
+    /// The index of the sprite in its `TextureAtlas`.
+    Atlas(usize),
+    /// The image of the sprite.
+    Image(Handle<Image>),
+}
+
+/// Swaps the `Sprite` of an entity as its decay of the track `T` (`Decay` by default)
+/// advances, so visual rot states need no custom system per item.
+///
+/// Each stage starts once the elapsed fraction of the decay reaches its threshold, from 0 to 1,
+/// and the sprite is only updated when entering a stage.
+#[derive(Component, Debug)]
+pub struct DecayStageSprites<T: Component = Decay> {
+    /// The stages as pairs of threshold and appearance, by ascending threshold.
+    stages: Vec<(f32, DecaySprite)>,
+    /// The index of the current stage, if any has started.
+    current: Option<usize>,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayStageSprites<T> {
+    /// Creates a new `DecayStageSprites` from pairs of threshold and appearance.
+    ///
+    /// The stages are sorted by threshold.
+    pub fn new(stages: impl IntoIterator<Item = (f32, DecaySprite)>) -> Self {
+        let mut stages: Vec<_> = stages.into_iter().collect();
+        stages.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Self {
+            stages,
+            current: None,
+            track: PhantomData,
+        }
+    }
+
+    /// Creates a new `DecayStageSprites` from evenly spread texture atlas indices, the first
+    /// one starting with the decay.
+    pub fn atlas(indices: impl IntoIterator<Item = usize>) -> Self {
+        let indices: Vec<_> = indices.into_iter().collect();
+        let count = indices.len() as f32;
+
+        Self::new(
+            indices
+                .into_iter()
+                .enumerate()
+                .map(|(stage, index)| (stage as f32 / count, DecaySprite::Atlas(index))),
+        )
+    }
+
+    /// Returns the stages as pairs of threshold and appearance.
+    pub fn stages(&self) -> &[(f32, DecaySprite)] {
+        &self.stages
+    }
+
+    /// Returns the index of the current stage, if any has started.
+    pub fn current(&self) -> Option<usize> {
+        self.current
+    }
+}
+
+/// System that updates the sprites of entities entering a new stage of their decay of the
+/// track `T`.
+pub(crate) fn update_stage_sprites<T: Component>(
+    mut query: Query<(&mut DecayStageSprites<T>, &DecayTimer<T>, &mut Sprite), With<T>>,
+) {
+    for (mut stages, timer, mut sprite) in query.iter_mut() {
+        let fraction = timer.fraction();
+        let stage = stages
+            .stages
+            .partition_point(|(threshold, _)| *threshold <= fraction)
+            .checked_sub(1);
+
+        if stage.is_none() || stage == stages.current {
+            continue;
+        }
+        stages.current = stage;
+
+        let Some((_, appearance)) = stage.and_then(|stage| stages.stages.get(stage)) else {
+            continue;
+        };
+
+        match appearance {
+            DecaySprite::Atlas(index) => {
+                if let Some(atlas) = sprite.texture_atlas.as_mut() {
+                    atlas.index = *index;
+                }
+            }
+            DecaySprite::Image(image) => sprite.image = image.clone(),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{Decay, DecayCompleted, DecayTimer};
+use bevy::prelude::*;
+use seldom_state::prelude::*;
+use std::marker::PhantomData;
+
+/// Plugin connecting the decay of the track `T` (`Decay` by default) to `seldom_state` state
+/// machines.
+///
+/// Once the decay of an entity with a `StateMachine` completes, `Done::Success` is inserted on
+/// it, so machines can transition with the `done(Some(Done::Success))` trigger. Transitions
+/// on the progress of the decay use the [`decay_fraction_at_least`] trigger instead. Use
+/// `DecayStateMachinePlugin::default()` for the `Decay` track and
+/// `DecayStateMachinePlugin::<T>::new()` for any other track.
+pub struct DecayStateMachinePlugin<T: Component = Decay> {
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+// `Default` is only implemented for the `Decay` track, so `DecayStateMachinePlugin::default()`
+// can be inferred without naming the track.
+#[allow(clippy::new_without_default)]
+impl<T: Component> DecayStateMachinePlugin<T> {
+    /// Creates a new `DecayStateMachinePlugin` for the decay track of the marker component `T`.
+    pub fn new() -> Self {
+        Self { track: PhantomData }
+    }
+}
+
+impl Default for DecayStateMachinePlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Component> Plugin for DecayStateMachinePlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(mark_state_machines_done::<T>);
+    }
+}
+
+/// Trigger of `seldom_state` transitions firing once the given fraction (from 0 to 1) of the
+/// decay of the track `T` has elapsed, such as `decay_fraction_at_least::<Decay>(0.5)` for an
+/// item turning stale halfway through its decay.
+///
+/// Entities that are not decaying on the track never fire it.
+pub fn decay_fraction_at_least<T: Component>(
+    threshold: f32,
+) -> impl Fn(In<Entity>, Query<&DecayTimer<T>>) -> bool + Clone + Send + Sync + 'static {
+    move |In(entity): In<Entity>, timers: Query<&DecayTimer<T>>| {
+        timers
+            .get(entity)
+            .is_ok_and(|timer| timer.fraction() >= threshold)
+    }
+}
+
+/// System that marks the state machines of the entities that have completed the decay of the
+/// track `T` as done.
+fn mark_state_machines_done<T: Component>(
+    trigger: Trigger<DecayCompleted<T>>,
+    mut commands: Commands,
+    machines: Query<(), With<StateMachine>>,
+) {
+    for &entity in trigger.iter() {
+        if machines.contains(entity) {
+            commands.entity(entity).insert(Done::Success);
+        }
+    }
+}
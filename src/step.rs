@@ -0,0 +1,77 @@
+use crate::{
+    clear_completion_guard, complete_decay, decaying, hold_group_barriers, idle, lane,
+    rate::{self, DecayModifierRegistry},
+    region, scan, tick_decay_delays, Decay, DecayBarriers, DecayCompletionGuard, DecayLodSchedule,
+    DecayLongLane, DecayScan,
+};
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use std::time::Duration;
+
+/// Steps the decay of the `Decay` track by `dt`, synchronously and without running the app
+/// schedules.
+///
+/// Meant for unit tests of decay-dependent logic: the `Time` resource is advanced by `dt`, the
+/// decay timers are ticked and the completions are applied, observers included, before
+/// returning. The `DecayPlugin` of the track is expected to be added, so decays are started
+/// and completion actions run as usual.
+pub fn step(world: &mut World, dt: Duration) {
+    step_track::<Decay>(world, dt);
+}
+
+/// Steps the decay of the track `T` by `dt`, as [`step`] does for the `Decay` track.
+pub fn step_track<T: Component>(world: &mut World, dt: Duration) {
+    advance_time(world, dt);
+    run_core(world);
+    run_track::<T>(world);
+}
+
+/// Advances the `Time` resource by `dt`, inserting it if missing.
+pub(crate) fn advance_time(world: &mut World, dt: Duration) {
+    world
+        .get_resource_or_insert_with(Time::<()>::default)
+        .advance_by(dt);
+}
+
+/// Runs the decay systems shared by every track once.
+pub(crate) fn run_core(world: &mut World) {
+    run(world, rate::expire_rate_effects);
+    if world.contains_resource::<DecayModifierRegistry>() {
+        run(world, rate::apply_registered_modifiers);
+    }
+    run(world, idle::track_idle);
+    if world
+        .run_system_once(region::any_decay_region)
+        .unwrap_or(false)
+    {
+        run(world, region::apply_decay_regions);
+    }
+    #[cfg(feature = "rapier")]
+    run(world, crate::rapier::apply_rest_gate);
+}
+
+/// Runs the decay systems of the track `T` once.
+pub(crate) fn run_track<T: Component>(world: &mut World) {
+    world.init_resource::<DecayCompletionGuard<T>>();
+    world.init_resource::<DecayBarriers<T>>();
+    world.init_resource::<DecayLodSchedule<T>>();
+
+    run(world, clear_completion_guard::<T>);
+    run(world, decaying::<T>);
+    if world.contains_resource::<DecayLongLane<T>>() {
+        run(world, lane::check_long_lane::<T>);
+    }
+    run(world, tick_decay_delays::<T>);
+    run(world, hold_group_barriers::<T>);
+    // The step may span many scan intervals, so scan the deadlines at once.
+    if world.contains_resource::<DecayScan<T>>() {
+        run(world, scan::rescan_decay_deadlines::<T>);
+    }
+    run(world, complete_decay::<T>);
+}
+
+/// Runs a decay system once on the world, applying its commands.
+fn run<M>(world: &mut World, system: impl IntoSystem<(), (), M>) {
+    if let Err(error) = world.run_system_once(system) {
+        panic!("could not step the decay: {error}");
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{commands, Decay, DecayEntityCommandsExt};
+use bevy::prelude::*;
+use std::{marker::PhantomData, time::Duration};
+
+/// Plugin pausing the decay of the track `T` (`Decay` by default) of entities while they bear
+/// the marker component `M` (`Unloaded` by default), and catching up on it once they lose it.
+///
+/// Meant for chunked or streamed worlds: tag the entities of a chunk with the marker when it
+/// is unloaded, and remove it when the chunk is loaded back. The span spent unloaded is then
+/// applied to the decay at once, completing it if it ran out meanwhile, so off-screen regions
+/// do not accumulate immortal litter. Use `DecayStreamingPlugin::default()` for the `Unloaded`
+/// marker and `DecayStreamingPlugin::<M, T>::new()` for any other marker or track.
+///
+/// Unloaded entities are paused through the `"chunk"` source of their `DecayPauseSources`, so
+/// other sources pausing them are left untouched; if the decay is still paused by another
+/// source once loaded, the span is not applied, as it would not have progressed either.
+pub struct DecayStreamingPlugin<M: Component = Unloaded, T: Component = Decay> {
+    /// Whether the span is measured in real time rather than in virtual time.
+    pub real_time: bool,
+    /// The marker component of unloaded entities, and the marker component of the decay track.
+    marker: PhantomData<(M, T)>,
+}
+
+// `Default` is only implemented for the `Unloaded` marker and the `Decay` track, so
+// `DecayStreamingPlugin::default()` can be inferred without naming them.
+#[allow(clippy::new_without_default)]
+impl<M: Component, T: Component> DecayStreamingPlugin<M, T> {
+    /// Creates a new `DecayStreamingPlugin` pausing the decay of the track `T` of entities
+    /// while they bear the marker component `M`.
+    pub fn new() -> Self {
+        Self {
+            real_time: false,
+            marker: PhantomData,
+        }
+    }
+
+    /// Measures the span spent unloaded in real time, so decays catch up even on the time the
+    /// virtual clock was paused or slowed down.
+    pub fn with_real_time(mut self) -> Self {
+        self.real_time = true;
+        self
+    }
+}
+
+impl Default for DecayStreamingPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: Component, T: Component> Plugin for DecayStreamingPlugin<M, T> {
+    fn build(&self, app: &mut App) {
+        if self.real_time {
+            app.add_observer(handle_unloaded::<M, T, Real>)
+                .add_observer(handle_loaded::<M, T, Real>);
+        } else {
+            app.add_observer(handle_unloaded::<M, T, Virtual>)
+                .add_observer(handle_loaded::<M, T, Virtual>);
+        }
+    }
+}
+
+/// The source pausing the decay of unloaded entities.
+const CHUNK_SOURCE: &str = "chunk";
+
+/// Marker component for entities whose chunk is unloaded, pausing their decay.
+#[derive(Component, Default, Debug)]
+pub struct Unloaded;
+
+/// Component recording when the entity was unloaded, on the clock of the
+/// `DecayStreamingPlugin` of the track `T`.
+#[derive(Component, Debug)]
+pub struct DecayUnloadedAt<T: Component = Decay> {
+    /// The elapsed time of the clock when the entity was unloaded.
+    pub at: Duration,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayUnloadedAt<T> {
+    /// Creates a new `DecayUnloadedAt` at the given elapsed time of the clock.
+    pub fn new(at: Duration) -> Self {
+        Self {
+            at,
+            track: PhantomData,
+        }
+    }
+}
+
+/// System that pauses the decay of an entity when it gains the marker `M`, recording when.
+fn handle_unloaded<M: Component, T: Component, C: Default + Send + Sync + 'static>(
+    trigger: Trigger<OnAdd, M>,
+    mut commands: Commands,
+    time: Res<Time<C>>,
+) {
+    commands
+        .entity(trigger.entity())
+        .pause_decay(CHUNK_SOURCE)
+        .insert(DecayUnloadedAt::<T>::new(time.elapsed()));
+}
+
+/// System that resumes the decay of an entity when it loses the marker `M`, applying the span
+/// spent unloaded.
+fn handle_loaded<M: Component, T: Component, C: Default + Send + Sync + 'static>(
+    trigger: Trigger<OnRemove, M>,
+    mut commands: Commands,
+    time: Res<Time<C>>,
+    query: Query<&DecayUnloadedAt<T>>,
+) {
+    let entity = trigger.entity();
+    let Ok(unloaded) = query.get(entity) else {
+        return;
+    };
+
+    // Decays still paused by other sources would not have progressed either, so they are
+    // left untouched.
+    let span = time.elapsed().saturating_sub(unloaded.at);
+    commands
+        .entity(entity)
+        .remove::<DecayUnloadedAt<T>>()
+        .resume_decay(CHUNK_SOURCE);
+    commands
+        .queue(move |world: &mut World| commands::fast_forward_entity::<T>(world, entity, span));
+}
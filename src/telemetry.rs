@@ -0,0 +1,54 @@
+use crate::{DecayCompleted, DecayCompletionGuard, DecayFrozen, DecayTimer};
+use bevy::prelude::*;
+use metrics::{counter, gauge, histogram};
+use std::{any::type_name, time::Duration};
+
+/// System that reports the active and paused decays of the track `T` as gauges.
+///
+/// Decays whose marker was removed or that are frozen keep their timer, and are counted as
+/// paused.
+pub(crate) fn report_active_decays<T: Component>(
+    query: Query<(&DecayTimer<T>, Has<T>, Has<DecayFrozen>)>,
+) {
+    let track = type_name::<T>();
+    let total = query.iter().len();
+    let paused = query
+        .iter()
+        .filter(|&(timer, marked, frozen)| !marked || frozen || timer.paused())
+        .count();
+
+    gauge!("ot_decay_active", "track" => track).set((total - paused) as f64);
+    gauge!("ot_decay_paused", "track" => track).set(paused as f64);
+}
+
+/// System that counts the completed decays of the track `T`.
+pub(crate) fn count_completions<T: Component>(trigger: Trigger<DecayCompleted<T>>) {
+    counter!("ot_decay_completions_total", "track" => type_name::<T>())
+        .increment(trigger.len() as u64);
+}
+
+/// System that counts the cancelled decays of the track `T`, whose timer was removed (or the
+/// entity despawned) without completing.
+///
+/// Removing the marker only pauses the decay, keeping its timer, so it is not counted.
+pub(crate) fn count_cancellations<T: Component>(
+    trigger: Trigger<OnRemove, DecayTimer<T>>,
+    guard: Res<DecayCompletionGuard<T>>,
+) {
+    // The timer of completed decays is removed along with the marker.
+    if !guard.contains(trigger.entity()) {
+        counter!("ot_decay_cancellations_total", "track" => type_name::<T>()).increment(1);
+    }
+}
+
+/// Records the time spent ticking the track `T`.
+pub(crate) fn record_tick_time<T: Component>(tick_time: Duration) {
+    histogram!("ot_decay_tick_seconds", "track" => type_name::<T>())
+        .record(tick_time.as_secs_f64());
+}
+
+/// Records how late a decay of the track `T` completed relative to its deadline.
+pub(crate) fn record_overshoot<T: Component>(overshoot: Duration) {
+    histogram!("ot_decay_completion_overshoot_seconds", "track" => type_name::<T>())
+        .record(overshoot.as_secs_f64());
+}
@@ -0,0 +1,99 @@
+use crate::{Decay, DecayTimer, DurationFormat};
+use bevy::prelude::*;
+use std::{borrow::Cow, marker::PhantomData, time::Duration};
+
+/// Keeps the `Text` or `Text2d` of an entity updated with the formatted remaining time of a
+/// decay of the track `T` (`Decay` by default), such as bomb timers or despawn warnings.
+///
+/// The decay displayed is the one of the `source` entity, or of this entity when unset, and
+/// the text is refreshed every `interval`. The text is left as is once the decay stops.
+#[derive(Component, Debug)]
+pub struct DecayCountdownText<T: Component = Decay> {
+    /// The entity whose decay is displayed, or `None` for this entity.
+    pub source: Option<Entity>,
+    /// The format of the remaining time.
+    pub format: DurationFormat,
+    /// The text displayed before the remaining time, such as `"Explodes in "`.
+    pub prefix: Cow<'static, str>,
+    /// The timer refreshing the text.
+    interval: Timer,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> Default for DecayCountdownText<T> {
+    fn default() -> Self {
+        Self {
+            source: None,
+            format: DurationFormat::default(),
+            prefix: Cow::Borrowed(""),
+            interval: Timer::new(Duration::ZERO, TimerMode::Repeating),
+            track: PhantomData,
+        }
+    }
+}
+
+impl<T: Component> DecayCountdownText<T> {
+    /// Creates a new `DecayCountdownText` displaying the decay of the given entity.
+    pub fn of(source: Entity) -> Self {
+        Self {
+            source: Some(source),
+            ..default()
+        }
+    }
+
+    /// Sets the format of the remaining time.
+    pub fn with_format(mut self, format: DurationFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the text displayed before the remaining time.
+    pub fn with_prefix(mut self, prefix: impl Into<Cow<'static, str>>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Refreshes the text every `interval` instead of every frame.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = Timer::new(interval, TimerMode::Repeating);
+        self
+    }
+}
+
+/// System that updates the countdown texts of decays of the track `T`.
+pub(crate) fn update_countdown_texts<T: Component>(
+    time: Res<Time>,
+    timers: Query<&DecayTimer<T>, With<T>>,
+    mut query: Query<(
+        Entity,
+        &mut DecayCountdownText<T>,
+        Option<&mut Text>,
+        Option<&mut Text2d>,
+    )>,
+) {
+    for (entity, mut countdown, text, text_2d) in query.iter_mut() {
+        countdown.interval.tick(time.delta());
+        if !countdown.interval.finished() && !countdown.interval.duration().is_zero() {
+            continue;
+        }
+
+        let Ok(timer) = timers.get(countdown.source.unwrap_or(entity)) else {
+            continue;
+        };
+
+        let formatted = format!(
+            "{}{}",
+            countdown.prefix,
+            countdown.format.format(timer.remaining())
+        );
+
+        // Only touch the text when it changed, keeping change detection meaningful.
+        if let Some(mut text) = text.filter(|text| text.0 != formatted) {
+            text.0.clone_from(&formatted);
+        }
+        if let Some(mut text) = text_2d.filter(|text| text.0 != formatted) {
+            text.0 = formatted;
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::DecayCompleted;
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::{TilePos, TileStorage, TileTextureIndex, TilemapId};
+
+/// Action executed on a `bevy_ecs_tilemap` tile once its decay completes, so terrain features
+/// like fire fields work on tile-based maps.
+///
+/// It is executed by every `DecayPlugin` executing completion actions, alongside the
+/// `DecayAction` of the tile.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DecayTileAction {
+    /// Swaps the texture of the tile to the given index, e.g. a fire becoming ashes.
+    SetTexture(u32),
+    /// Removes the tile from its tilemap and despawns it.
+    Remove,
+}
+
+/// System that executes the `DecayTileAction` of tiles that have completed decaying.
+pub(crate) fn handle_decay_tile_action<T: Component>(
+    trigger: Trigger<DecayCompleted<T>>,
+    mut commands: Commands,
+    tiles: Query<(&DecayTileAction, &TilePos, &TilemapId)>,
+    mut storages: Query<&mut TileStorage>,
+) {
+    for &entity in trigger.iter() {
+        let Ok((action, position, tilemap)) = tiles.get(entity) else {
+            continue;
+        };
+
+        match action {
+            // The action is consumed along with the texture swap.
+            DecayTileAction::SetTexture(index) => {
+                commands
+                    .entity(entity)
+                    .insert(TileTextureIndex(*index))
+                    .remove::<DecayTileAction>();
+            }
+            // Free the position of the tile before despawning it.
+            DecayTileAction::Remove => {
+                if let Ok(mut storage) = storages.get_mut(tilemap.0) {
+                    storage.remove(position);
+                }
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::DecayTimer;
+use bevy::prelude::*;
+use bevy_time_runner::TimeRunner;
+use std::time::Duration;
+
+/// Marker component delegating the time progression of the decays of an entity to its
+/// `bevy_time_runner` `TimeRunner`.
+///
+/// Every tick, the elapsed time of the decay timers is set to the elapsed time of the runner,
+/// so the decay follows the skips, reversals and time scaling applied to the runner, like the
+/// rest of a time-manipulation-heavy game. Reversing the runner past the end of the decay
+/// un-finishes it, as long as it has not completed yet. The runner should be as long as the
+/// decay, and is inserted alongside this marker; its elapsed time is read as last ticked, in
+/// the previous frame.
+#[derive(Component, Default, Clone, Copy, Debug)]
+pub struct DecayTimeRunner;
+
+/// System that sets the elapsed time of the decay timers of the track `T` from the runners of
+/// their entities.
+pub(crate) fn sync_time_runners<T: Component>(
+    mut query: Query<(&mut DecayTimer<T>, &TimeRunner), (With<T>, With<DecayTimeRunner>)>,
+) {
+    for (mut timer, runner) in query.iter_mut() {
+        // Paused decays keep their elapsed time until they resume.
+        if timer.paused() {
+            continue;
+        }
+
+        let elapsed = Duration::from_secs_f32(runner.elapsed().now().max(0.0));
+        let elapsed = elapsed.min(timer.duration());
+        // Only touch the timers out of sync with their runner, keeping change detection.
+        if elapsed == timer.elapsed() && timer.finished() == (elapsed >= timer.duration()) {
+            continue;
+        }
+
+        // Restarting clears the finished state, which an empty tick then updates.
+        timer.restart_at(elapsed);
+        timer.tick(Duration::ZERO);
+    }
+}
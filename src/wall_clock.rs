@@ -0,0 +1,165 @@
+use crate::{Decay, DecayDuration, DecayTimer};
+use bevy::prelude::*;
+use std::{
+    fmt,
+    marker::PhantomData,
+    time::{Duration, SystemTime},
+};
+
+/// Anchors the decay of the track `T` (`Decay` by default) to an absolute wall-clock expiry.
+///
+/// Meant for long decays (hours or days): every frame, the expiry is reconciled against the
+/// wall clock, so a long hitch or a server restart does not extend the lifetime of the entity.
+/// Whenever the timer lags behind the wall clock by more than the `tolerance`, it is advanced
+/// to match; smaller differences, such as slowed down decays, simply move the expiry.
+///
+/// While the decay is paused, the expiry is cleared and anchored again once it resumes. To
+/// survive restarts, persist the [`expires_at`](Self::expires_at) time and restore it with
+/// [`DecayWallClock::at`].
+#[derive(Component)]
+pub struct DecayWallClock<T: Component = Decay> {
+    /// The wall-clock time at which the decay expires, if anchored.
+    expires_at: Option<SystemTime>,
+    /// The lag behind the wall clock tolerated before advancing the timer.
+    pub tolerance: Duration,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> Default for DecayWallClock<T> {
+    fn default() -> Self {
+        Self {
+            expires_at: None,
+            tolerance: Duration::from_secs(1),
+            track: PhantomData,
+        }
+    }
+}
+
+impl<T: Component> DecayWallClock<T> {
+    /// Creates a new `DecayWallClock`, anchored once the decay is running.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `DecayWallClock` expiring at the given wall-clock time, such as one
+    /// persisted before a restart.
+    pub fn at(expires_at: SystemTime) -> Self {
+        Self {
+            expires_at: Some(expires_at),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the wall-clock time at which the decay expires, if anchored.
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        self.expires_at
+    }
+}
+
+impl<T: Component> fmt::Debug for DecayWallClock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecayWallClock")
+            .field("expires_at", &self.expires_at)
+            .field("tolerance", &self.tolerance)
+            .finish()
+    }
+}
+
+/// Schedules the decay of the track `T` (`Decay` by default) to complete at a specific
+/// wall-clock time, such as the daily reset of a chest or the end of an event.
+///
+/// Every frame, the timer is reconciled against the wall clock, whichever duration the decay
+/// started with: whenever its remaining time differs from the time left until the expiry by
+/// more than the `tolerance`, the duration of the timer is adjusted, keeping its elapsed time.
+/// Unlike the `DecayWallClock`, the expiry never moves, so pauses, speed modifiers and changes
+/// of the system clock are all corrected; a decay paused past its expiry completes as soon as
+/// it resumes.
+///
+/// The decay starts with the time left until the expiry as its duration, regardless of its
+/// `DecayDuration`, which is required so the decay starts on its own.
+#[derive(Component)]
+#[require(DecayDuration)]
+pub struct DecayAt<T: Component = Decay> {
+    /// The wall-clock time at which the decay completes.
+    pub at: SystemTime,
+    /// The difference with the wall clock tolerated before adjusting the timer.
+    pub tolerance: Duration,
+    /// The marker component of the decay track.
+    track: PhantomData<T>,
+}
+
+impl<T: Component> DecayAt<T> {
+    /// Creates a new `DecayAt` completing the decay at the given wall-clock time.
+    pub fn new(at: SystemTime) -> Self {
+        Self {
+            at,
+            tolerance: Duration::from_millis(100),
+            track: PhantomData,
+        }
+    }
+
+    /// Returns the time left until the expiry, according to the wall clock.
+    pub fn remaining(&self) -> Duration {
+        self.at
+            .duration_since(SystemTime::now())
+            .unwrap_or_default()
+    }
+}
+
+impl<T: Component> fmt::Debug for DecayAt<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecayAt")
+            .field("at", &self.at)
+            .field("tolerance", &self.tolerance)
+            .finish()
+    }
+}
+
+/// System that reconciles the decay timers of the track `T` against their wall-clock expiry.
+pub(crate) fn reconcile_wall_clock<T: Component>(
+    mut query: Query<(&mut DecayTimer<T>, &mut DecayWallClock<T>), With<T>>,
+) {
+    let now = SystemTime::now();
+
+    for (mut timer, mut wall_clock) in query.iter_mut() {
+        // Paused decays are anchored again once they resume.
+        if timer.paused() {
+            wall_clock.expires_at = None;
+            continue;
+        }
+
+        if let Some(expires_at) = wall_clock.expires_at {
+            // Advance the timer if it lags too far behind the wall clock.
+            let wall_remaining = expires_at.duration_since(now).unwrap_or_default();
+            if timer.remaining() > wall_remaining + wall_clock.tolerance {
+                let elapsed = timer.duration().saturating_sub(wall_remaining);
+                timer.restart_at(elapsed);
+            }
+        }
+
+        wall_clock.expires_at = Some(now + timer.remaining());
+    }
+}
+
+/// System that reconciles the decay timers of the track `T` against their scheduled expiry.
+pub(crate) fn reconcile_decay_at<T: Component>(
+    mut query: Query<(&mut DecayTimer<T>, &DecayAt<T>), With<T>>,
+) {
+    let now = SystemTime::now();
+
+    for (mut timer, decay_at) in query.iter_mut() {
+        // Paused decays are reconciled once they resume.
+        if timer.paused() {
+            continue;
+        }
+
+        let wall_remaining = decay_at.at.duration_since(now).unwrap_or_default();
+        if timer.remaining().abs_diff(wall_remaining) > decay_at.tolerance {
+            // Keep the elapsed time, so stages based on it do not jump back.
+            let elapsed = timer.elapsed();
+            timer.set_duration(elapsed + wall_remaining);
+            timer.restart_at(elapsed);
+        }
+    }
+}
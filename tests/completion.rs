@@ -0,0 +1,187 @@
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use ot_decay::prelude::*;
+use std::time::Duration;
+
+/// Marker component of a second decay track.
+#[derive(Component, Default)]
+struct Rot;
+
+/// Marker component cancelling the decay of the `Decay` track.
+#[derive(Component)]
+struct Preserved;
+
+/// Events recorded by the observers of the test app.
+#[derive(Resource, Default)]
+struct Recorded {
+    /// The completions of the `Decay` track.
+    completed: Vec<Entity>,
+    /// The completions of the `Rot` track.
+    rot_completed: Vec<Entity>,
+    /// The pauses of both tracks.
+    paused: Vec<Entity>,
+}
+
+/// Creates an app ticking both tracks by 100 milliseconds each frame.
+fn app() -> App {
+    app_with(DecayPlugin::default())
+}
+
+/// Creates an app ticking both tracks by 100 milliseconds each frame, with the given plugin for
+/// the `Decay` track.
+fn app_with(plugin: DecayPlugin) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, plugin, DecayPlugin::<Rot>::new()))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .init_resource::<Recorded>()
+        .add_observer(
+            |trigger: Trigger<DecayCompleted>, mut recorded: ResMut<Recorded>| {
+                recorded.completed.extend(trigger.iter().copied());
+            },
+        )
+        .add_observer(
+            |trigger: Trigger<DecayCompleted<Rot>>, mut recorded: ResMut<Recorded>| {
+                recorded.rot_completed.extend(trigger.iter().copied());
+            },
+        )
+        .add_observer(
+            |trigger: Trigger<DecayPaused>, mut recorded: ResMut<Recorded>| {
+                recorded.paused.push(trigger.event().entity);
+            },
+        )
+        .add_observer(
+            |trigger: Trigger<DecayPaused<Rot>>, mut recorded: ResMut<Recorded>| {
+                recorded.paused.push(trigger.event().entity);
+            },
+        );
+    app
+}
+
+/// Runs the given number of frames.
+fn run(app: &mut App, frames: usize) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+#[test]
+fn completion_does_not_report_a_pause() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn(Decay::for_duration(Duration::from_secs(1)))
+        .id();
+
+    run(&mut app, 20);
+
+    let recorded = app.world().resource::<Recorded>();
+    assert_eq!(recorded.completed, [entity]);
+    assert!(recorded.paused.is_empty());
+}
+
+#[test]
+fn pause_in_completion_frame_completes_once_on_resume() {
+    let mut app = app();
+    app.add_systems(
+        PreUpdate,
+        (|mut commands: Commands, query: Query<(Entity, &DecayTimer), With<Decay>>| {
+            for (entity, timer) in query.iter() {
+                if timer.remaining().is_zero() {
+                    commands.entity(entity).remove::<Decay>();
+                }
+            }
+        })
+        .after(DecayingSet::Tick)
+        .before(DecayingSet::Complete),
+    );
+
+    let entity = app
+        .world_mut()
+        .spawn(Decay::for_duration(Duration::from_secs(1)))
+        .id();
+
+    // The timer finishes, but the marker is removed before the completion.
+    run(&mut app, 20);
+    {
+        let recorded = app.world().resource::<Recorded>();
+        assert!(recorded.completed.is_empty());
+        assert_eq!(recorded.paused, [entity]);
+    }
+
+    // Resuming the finished decay completes it, and only once.
+    app.world_mut().entity_mut(entity).insert(Decay);
+    run(&mut app, 5);
+
+    let recorded = app.world().resource::<Recorded>();
+    assert_eq!(recorded.completed, [entity]);
+    assert_eq!(recorded.paused, [entity]);
+}
+
+#[test]
+fn marker_readded_in_completion_frame_starts_a_new_cycle() {
+    let mut app = app();
+    app.add_observer(|trigger: Trigger<DecayCompleted>, mut commands: Commands| {
+        for &entity in trigger.iter() {
+            commands.entity(entity).insert(Decay);
+        }
+    });
+
+    let entity = app
+        .world_mut()
+        .spawn(Decay::for_duration(Duration::from_secs(1)))
+        .id();
+
+    // The first cycle completes once, and the new cycle is running.
+    run(&mut app, 15);
+    assert_eq!(app.world().resource::<Recorded>().completed, [entity]);
+    assert!(app.world().get::<DecayTimer>(entity).is_some());
+    assert!(app.world().resource::<Recorded>().paused.is_empty());
+
+    // The new cycle completes once more, a full duration later.
+    run(&mut app, 10);
+    assert_eq!(
+        app.world().resource::<Recorded>().completed,
+        [entity, entity]
+    );
+}
+
+#[test]
+fn two_tracks_complete_once_each() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((Decay, Rot, DecayDuration::new(Duration::from_secs(1))))
+        .id();
+
+    run(&mut app, 20);
+
+    let recorded = app.world().resource::<Recorded>();
+    assert_eq!(recorded.completed, [entity]);
+    assert_eq!(recorded.rot_completed, [entity]);
+    assert!(recorded.paused.is_empty());
+    assert!(app.world().get::<DecayTimer>(entity).is_none());
+    assert!(app.world().get::<DecayTimer<Rot>>(entity).is_none());
+}
+
+#[test]
+fn cancel_does_not_report_a_pause() {
+    let mut app = app_with(DecayPlugin::default().cancel_on::<Preserved>());
+    let entity = app
+        .world_mut()
+        .spawn((
+            Decay::for_duration(Duration::from_secs(5)),
+            CancelDecayOn::<Preserved>::default(),
+        ))
+        .id();
+
+    run(&mut app, 5);
+    app.world_mut().entity_mut(entity).insert(Preserved);
+    run(&mut app, 1);
+
+    assert!(app.world().get::<Decay>(entity).is_none());
+    assert!(app.world().get::<DecayTimer>(entity).is_none());
+    let recorded = app.world().resource::<Recorded>();
+    assert!(recorded.completed.is_empty());
+    assert!(recorded.paused.is_empty());
+}
@@ -0,0 +1,112 @@
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use ot_decay::prelude::*;
+use std::{env, process::Command, time::Duration};
+
+/// Environment variable holding the number of threads of the simulation run by
+/// `simulate_in_child`, which does nothing without it.
+const THREADS_VAR: &str = "OT_DECAY_TEST_THREADS";
+
+/// Prefix of the lines printed by the simulation.
+const PREFIX: &str = "decay-event";
+
+/// Events recorded by the observers of the simulation, in the order they were triggered.
+#[derive(Resource, Default)]
+struct Recorded(Vec<String>);
+
+/// Runs the simulation on a task pool with the given number of threads, printing every
+/// completion and consumed stack followed by the remaining timers.
+fn simulate(threads: usize) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins.set(TaskPoolPlugin {
+            task_pool_options: TaskPoolOptions::with_num_threads(threads),
+        }),
+        DecayPlugin::default(),
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )))
+    .init_resource::<Recorded>()
+    .add_observer(
+        |trigger: Trigger<DecayCompleted>, mut recorded: ResMut<Recorded>| {
+            for entity in trigger.iter() {
+                recorded.0.push(format!("completed {entity}"));
+            }
+        },
+    )
+    .add_observer(
+        |trigger: Trigger<DecayStackConsumed>, mut recorded: ResMut<Recorded>| {
+            let event = trigger.event();
+            recorded.0.push(format!(
+                "stack {} {} {:?}",
+                event.entity, event.remaining_stacks, event.duration
+            ));
+        },
+    );
+
+    // Many decays finish on the same frames, so the completions are spread across threads.
+    for index in 0..4000_u64 {
+        let mut entity = app.world_mut().spawn((
+            Decay,
+            DecayDuration::new(Duration::from_millis(100 * (index % 20 + 1))),
+        ));
+        if index % 3 == 0 {
+            entity.insert(DecayStacks(2));
+        }
+    }
+
+    for _ in 0..30 {
+        app.update();
+    }
+
+    for line in &app.world().resource::<Recorded>().0 {
+        println!("{PREFIX} {line}");
+    }
+
+    let mut timers = app.world_mut().query::<(Entity, &DecayTimer)>();
+    let mut timers: Vec<_> = timers
+        .iter(app.world())
+        .map(|(entity, timer)| format!("timer {entity} {:?}", timer.elapsed()))
+        .collect();
+    timers.sort();
+    for line in timers {
+        println!("{PREFIX} {line}");
+    }
+}
+
+/// Runs the simulation in a child process, as the task pools are global to the process,
+/// returning the printed lines.
+fn simulate_in_process(threads: usize) -> Vec<String> {
+    let output = Command::new(env::current_exe().unwrap())
+        .args(["simulate_in_child", "--exact", "--nocapture"])
+        .env(THREADS_VAR, threads.to_string())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .filter(|line| line.starts_with(PREFIX))
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[test]
+fn simulate_in_child() {
+    let Ok(threads) = env::var(THREADS_VAR) else {
+        return;
+    };
+
+    simulate(threads.parse().unwrap());
+}
+
+#[test]
+fn completions_do_not_depend_on_thread_count() {
+    let single = simulate_in_process(1);
+    let multi = simulate_in_process(8);
+
+    assert!(single.iter().any(|line| line.contains("completed")));
+    assert!(single.iter().any(|line| line.contains("stack")));
+    assert_eq!(single, multi);
+}
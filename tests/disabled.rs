@@ -0,0 +1,191 @@
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use ot_decay::{prelude::*, DecayDelayTimer};
+use std::time::Duration;
+
+/// Marker component disabling entities.
+#[derive(Component)]
+struct Disabled;
+
+/// Marker component of a second decay track, which keeps ticking while disabled.
+#[derive(Component, Default)]
+struct Rot;
+
+/// Completions of the `Decay` track.
+#[derive(Resource, Default)]
+struct Completed(Vec<Entity>);
+
+/// Creates an app ticking both tracks by 100 milliseconds each frame, with the `Decay` track
+/// following the given policy while disabled.
+fn app(policy: DecayWhileDisabled) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        DecayPlugin::default().with_disabling::<Disabled>(policy),
+        DecayPlugin::<Rot>::new(),
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )))
+    .init_resource::<Completed>()
+    .add_observer(
+        |trigger: Trigger<DecayCompleted>, mut completed: ResMut<Completed>| {
+            completed.0.extend(trigger.iter().copied());
+        },
+    );
+    app
+}
+
+/// Runs the given number of frames.
+fn run(app: &mut App, frames: usize) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+/// Spawns an entity decaying on both tracks for the given duration, and runs until 400
+/// milliseconds have elapsed.
+fn spawn(app: &mut App, duration: Duration) -> Entity {
+    let entity = app
+        .world_mut()
+        .spawn((Decay, Rot, DecayDuration::new(duration)))
+        .id();
+
+    // The first frame has no elapsed time.
+    run(app, 5);
+    entity
+}
+
+/// Returns the elapsed time of the decay of the track `T` of the entity, in seconds.
+fn elapsed<T: Component>(app: &App, entity: Entity) -> f32 {
+    app.world()
+        .get::<DecayTimer<T>>(entity)
+        .unwrap()
+        .elapsed()
+        .as_secs_f32()
+}
+
+/// Checks that two times in seconds are equal, within a millisecond.
+fn assert_secs(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 0.001,
+        "expected {expected}s, got {actual}s"
+    );
+}
+
+#[test]
+fn disabled_decay_catches_up_once_enabled() {
+    let mut app = app(DecayWhileDisabled::CatchUp);
+    let entity = spawn(&mut app, Duration::from_secs(5));
+    assert_secs(elapsed::<Decay>(&app, entity), 0.4);
+
+    // While disabled, only the other track keeps decaying.
+    app.world_mut().entity_mut(entity).insert(Disabled);
+    run(&mut app, 10);
+    assert!(app.world().get::<DecayDisabled>(entity).is_some());
+    assert_secs(elapsed::<Decay>(&app, entity), 0.4);
+    assert_secs(elapsed::<Rot>(&app, entity), 1.4);
+
+    // Once enabled, the span spent disabled is applied at once.
+    app.world_mut().entity_mut(entity).remove::<Disabled>();
+    assert!(app.world().get::<DecayDisabled>(entity).is_none());
+    assert_secs(elapsed::<Decay>(&app, entity), 1.4);
+
+    run(&mut app, 1);
+    assert_secs(elapsed::<Decay>(&app, entity), 1.5);
+}
+
+#[test]
+fn disabled_decay_completes_once_enabled_when_it_ran_out() {
+    let mut app = app(DecayWhileDisabled::CatchUp);
+    let entity = spawn(&mut app, Duration::from_secs(1));
+
+    app.world_mut().entity_mut(entity).insert(Disabled);
+    run(&mut app, 10);
+    assert!(app.world().resource::<Completed>().0.is_empty());
+
+    app.world_mut().entity_mut(entity).remove::<Disabled>();
+    assert_eq!(app.world().resource::<Completed>().0, [entity]);
+    assert!(app.world().get::<DecayTimer>(entity).is_none());
+}
+
+#[test]
+fn disabled_decay_skips_the_span_without_catch_up() {
+    let mut app = app(DecayWhileDisabled::Skip);
+    let entity = spawn(&mut app, Duration::from_secs(1));
+
+    app.world_mut().entity_mut(entity).insert(Disabled);
+    run(&mut app, 10);
+    assert_secs(elapsed::<Decay>(&app, entity), 0.4);
+
+    // Once enabled, the decay resumes where it was left.
+    app.world_mut().entity_mut(entity).remove::<Disabled>();
+    assert!(app.world().get::<DecayDisabled>(entity).is_none());
+    assert_secs(elapsed::<Decay>(&app, entity), 0.4);
+    assert!(app.world().resource::<Completed>().0.is_empty());
+
+    run(&mut app, 1);
+    assert_secs(elapsed::<Decay>(&app, entity), 0.5);
+}
+
+#[test]
+fn disabled_decay_keeps_ticking() {
+    let mut app = app(DecayWhileDisabled::KeepTicking);
+    let entity = spawn(&mut app, Duration::from_secs(5));
+
+    app.world_mut().entity_mut(entity).insert(Disabled);
+    run(&mut app, 10);
+    assert!(app.world().get::<DecayDisabled>(entity).is_none());
+    assert_secs(elapsed::<Decay>(&app, entity), 1.4);
+
+    app.world_mut().entity_mut(entity).remove::<Disabled>();
+    assert_secs(elapsed::<Decay>(&app, entity), 1.4);
+}
+
+#[test]
+fn disabled_decay_holds_its_delay() {
+    let mut app = app(DecayWhileDisabled::Skip);
+    let entity = app
+        .world_mut()
+        .spawn((
+            Decay,
+            Rot,
+            DecayDuration::new(Duration::from_secs(5)),
+            DecayDelay(Duration::from_secs(1)),
+        ))
+        .id();
+    run(&mut app, 5);
+    let delay = |app: &App| {
+        app.world()
+            .get::<DecayDelayTimer>(entity)
+            .unwrap()
+            .elapsed()
+            .as_secs_f32()
+    };
+    let before = delay(&app);
+
+    // While disabled, the delay is not counted down, while the other track starts decaying.
+    app.world_mut().entity_mut(entity).insert(Disabled);
+    run(&mut app, 10);
+    assert_secs(delay(&app), before);
+    assert!(app.world().get::<DecayTimer>(entity).is_none());
+    assert!(app.world().get::<DecayTimer<Rot>>(entity).is_some());
+
+    // Once enabled, the delay resumes where it was left.
+    app.world_mut().entity_mut(entity).remove::<Disabled>();
+    run(&mut app, 1);
+    assert_secs(delay(&app), before + 0.1);
+}
+
+#[test]
+fn decay_started_while_disabled_is_skipped() {
+    let mut app = app(DecayWhileDisabled::Skip);
+    let entity = app.world_mut().spawn(Disabled).id();
+    run(&mut app, 1);
+
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(Decay::for_duration(Duration::from_secs(1)));
+    assert!(app.world().get::<DecayDisabled>(entity).is_some());
+    run(&mut app, 15);
+    assert!(app.world().resource::<Completed>().0.is_empty());
+}
@@ -0,0 +1,124 @@
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use ot_decay::prelude::*;
+use std::time::Duration;
+
+/// Events recorded by the observers of the test app.
+#[derive(Resource, Default)]
+struct Recorded {
+    /// The completed decays, in order.
+    completed: Vec<Entity>,
+    /// The stacks left after each consumed stack, in order.
+    stacks: Vec<(Entity, u32)>,
+}
+
+/// Creates an app ticking the decay by 100 milliseconds each frame.
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, DecayPlugin::default()))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .init_resource::<Recorded>()
+        .add_observer(
+            |trigger: Trigger<DecayCompleted>, mut recorded: ResMut<Recorded>| {
+                recorded.completed.extend(trigger.iter().copied());
+            },
+        )
+        .add_observer(
+            |trigger: Trigger<DecayStackConsumed>, mut recorded: ResMut<Recorded>| {
+                let event = trigger.event();
+                recorded.stacks.push((event.entity, event.remaining_stacks));
+            },
+        );
+
+    // The first frame has no elapsed time.
+    app.update();
+    app
+}
+
+/// Fast-forwards the decay of every entity by the given number of milliseconds.
+fn fast_forward(app: &mut App, millis: u64) {
+    app.world_mut()
+        .commands()
+        .fast_forward_decay::<()>(Duration::from_millis(millis));
+    app.world_mut().flush();
+}
+
+/// Returns the elapsed time of the decay of the entity, in seconds.
+fn elapsed(app: &App, entity: Entity) -> f32 {
+    app.world()
+        .get::<DecayTimer>(entity)
+        .unwrap()
+        .elapsed()
+        .as_secs_f32()
+}
+
+/// Checks that two times in seconds are equal, within a millisecond.
+fn assert_secs(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 0.001,
+        "expected {expected}s, got {actual}s"
+    );
+}
+
+#[test]
+fn fast_forward_completes_in_chronological_order() {
+    let mut app = app();
+    let slow = app
+        .world_mut()
+        .spawn(Decay::for_duration(Duration::from_secs(3)))
+        .id();
+    let fast = app
+        .world_mut()
+        .spawn(Decay::for_duration(Duration::from_secs(1)))
+        .id();
+
+    fast_forward(&mut app, 5000);
+    assert_eq!(app.world().resource::<Recorded>().completed, [fast, slow]);
+}
+
+#[test]
+fn fast_forward_consumes_stacks() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((Decay::for_duration(Duration::from_secs(1)), DecayStacks(3)))
+        .id();
+
+    // Two stacks are consumed, and the last one is halfway through.
+    fast_forward(&mut app, 2500);
+    let recorded = app.world().resource::<Recorded>();
+    assert_eq!(recorded.stacks, [(entity, 2), (entity, 1)]);
+    assert!(recorded.completed.is_empty());
+    assert_eq!(
+        app.world().get::<DecayStacks>(entity),
+        Some(&DecayStacks(1))
+    );
+    assert_secs(elapsed(&app, entity), 0.5);
+
+    // The last stack completes the decay.
+    app.update();
+    fast_forward(&mut app, 1000);
+    assert_eq!(app.world().resource::<Recorded>().completed, [entity]);
+}
+
+#[test]
+fn fast_forward_follows_the_speed_schedule() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Decay::for_duration(Duration::from_secs(10)),
+            DecaySpeedSchedule::new([(0.5, 0.5), (1.0, 2.0)]),
+        ))
+        .id();
+
+    // The first half decays at half speed over 10 seconds, and the second half at double
+    // speed over 2.5 seconds, even within a single fast-forward.
+    fast_forward(&mut app, 12000);
+    assert_secs(elapsed(&app, entity), 9.0);
+    assert!(app.world().resource::<Recorded>().completed.is_empty());
+
+    fast_forward(&mut app, 500);
+    assert_eq!(app.world().resource::<Recorded>().completed, [entity]);
+}
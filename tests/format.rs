@@ -0,0 +1,41 @@
+use ot_decay::prelude::*;
+use std::time::Duration;
+
+/// Formats the given number of milliseconds with the format.
+fn format(duration_format: DurationFormat, millis: u64) -> String {
+    duration_format.format(Duration::from_millis(millis))
+}
+
+#[test]
+fn durations_are_formatted_with_the_largest_units() {
+    let default = DurationFormat::default();
+    assert_eq!(format(default, 150_000), "2m 30s");
+    assert_eq!(format(default, 3 * 3_600_000), "3h");
+    assert_eq!(format(default, 93_784_000), "1d 2h");
+}
+
+#[test]
+fn durations_are_rounded_up_to_the_smallest_unit() {
+    assert_eq!(format(DurationFormat::default(), 1_200), "2s");
+    assert_eq!(format(DurationFormat::default(), 0), "0s");
+
+    let minutes = DurationFormat {
+        smallest_unit: TimeUnit::Minutes,
+        max_units: 2,
+    };
+    assert_eq!(format(minutes, 61_000), "2m");
+    assert_eq!(format(minutes, 0), "0m");
+}
+
+#[test]
+fn zero_units_count_towards_the_limit() {
+    // The zero hours are omitted, but still count as the second unit.
+    let default = DurationFormat::default();
+    assert_eq!(format(default, 86_700_000), "1d");
+
+    let three = DurationFormat {
+        smallest_unit: TimeUnit::Seconds,
+        max_units: 3,
+    };
+    assert_eq!(format(three, 86_700_000), "1d 5m");
+}
@@ -0,0 +1,74 @@
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use ot_decay::prelude::*;
+use std::time::Duration;
+
+/// Completions of the `Decay` track.
+#[derive(Resource, Default)]
+struct Completed(Vec<Entity>);
+
+/// Creates an app ticking the decay by 100 milliseconds each frame.
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, DecayPlugin::default()))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .init_resource::<Completed>()
+        .add_observer(
+            |trigger: Trigger<DecayCompleted>, mut completed: ResMut<Completed>| {
+                completed.0.extend(trigger.iter().copied());
+            },
+        );
+    app
+}
+
+/// Returns the elapsed time of the decay of the entity, in seconds.
+fn elapsed(app: &App, entity: Entity) -> f32 {
+    app.world()
+        .get::<DecayTimer>(entity)
+        .unwrap()
+        .elapsed()
+        .as_secs_f32()
+}
+
+#[test]
+fn level_of_detail_does_not_change_outcomes() {
+    let mut app = app();
+    let precise = app
+        .world_mut()
+        .spawn(Decay::for_duration(Duration::from_millis(2500)))
+        .id();
+    let detailed = app
+        .world_mut()
+        .spawn((
+            Decay::for_duration(Duration::from_millis(2500)),
+            DecayLod::new(Duration::from_secs(1)),
+        ))
+        .id();
+
+    // The first frame has no elapsed time.
+    app.update();
+
+    for frame in 1..=25 {
+        app.update();
+
+        // Between its ticks, the entity waits out of the per-frame ticking.
+        if frame % 10 != 0 && frame < 25 {
+            assert!(app.world().get::<DecayLodWaiting>(detailed).is_some());
+        }
+
+        // Once ticked, it has decayed as much as the precise entity.
+        if frame % 10 == 0 {
+            let (precise, detailed) = (elapsed(&app, precise), elapsed(&app, detailed));
+            assert!((precise - detailed).abs() < 0.001);
+        }
+
+        // Both complete on the same frame.
+        let completed = &app.world().resource::<Completed>().0;
+        if frame < 25 {
+            assert!(completed.is_empty(), "completed early on frame {frame}");
+        } else {
+            assert_eq!(completed, &[precise, detailed]);
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use ot_decay::prelude::*;
+use std::time::Duration;
+
+/// Events recorded by the observers of the test app.
+#[derive(Resource, Default)]
+struct Recorded {
+    /// The pauses, with their reason.
+    paused: Vec<(Entity, DecayPauseReason)>,
+    /// The resumed decays.
+    resumed: Vec<Entity>,
+}
+
+/// Creates an app ticking the decay by 100 milliseconds each frame.
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, DecayPlugin::default()))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .init_resource::<Recorded>()
+        .add_observer(
+            |trigger: Trigger<DecayPaused>, mut recorded: ResMut<Recorded>| {
+                let event = trigger.event();
+                recorded.paused.push((event.entity, event.reason));
+            },
+        )
+        .add_observer(
+            |trigger: Trigger<DecayStarted>, mut recorded: ResMut<Recorded>| {
+                let event = trigger.event();
+                if event.resumed {
+                    recorded.resumed.push(event.entity);
+                }
+            },
+        );
+    app
+}
+
+/// Runs the given number of frames.
+fn run(app: &mut App, frames: usize) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+#[test]
+fn freezing_a_group_pauses_its_members() {
+    let mut app = app();
+    let group = app.world_mut().spawn_empty().id();
+    let member = app
+        .world_mut()
+        .spawn((
+            Decay::for_duration(Duration::from_secs(5)),
+            DecayGroup(group),
+        ))
+        .id();
+    run(&mut app, 3);
+
+    app.world_mut().entity_mut(group).insert(DecayFrozen);
+    let sources = app.world().get::<DecayPauseSources>(member).unwrap();
+    assert!(sources.contains("decay group"));
+    assert!(app.world().get::<DecayTimer>(member).unwrap().paused());
+    assert_eq!(
+        app.world().resource::<Recorded>().paused,
+        [(member, DecayPauseReason::GroupPaused)]
+    );
+
+    app.world_mut().entity_mut(group).remove::<DecayFrozen>();
+    assert!(app.world().get::<DecayFrozen>(member).is_none());
+    assert!(!app.world().get::<DecayTimer>(member).unwrap().paused());
+    assert_eq!(app.world().resource::<Recorded>().resumed, [member]);
+}
+
+#[test]
+fn pausing_the_virtual_clock_is_a_global_pause() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn(Decay::for_duration(Duration::from_secs(5)))
+        .id();
+    run(&mut app, 3);
+
+    app.world_mut().resource_mut::<Time<Virtual>>().pause();
+    run(&mut app, 3);
+    assert_eq!(
+        app.world().resource::<Recorded>().paused,
+        [(entity, DecayPauseReason::GlobalPause)]
+    );
+
+    app.world_mut().resource_mut::<Time<Virtual>>().unpause();
+    run(&mut app, 1);
+    assert_eq!(app.world().resource::<Recorded>().resumed, [entity]);
+}
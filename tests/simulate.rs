@@ -0,0 +1,84 @@
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use ot_decay::{prelude::*, simulate};
+use std::time::Duration;
+
+/// One day, in seconds.
+const DAY: u64 = 24 * 60 * 60;
+
+/// Completions of the `Decay` track, with the elapsed time when they happened.
+#[derive(Resource, Default)]
+struct Completed(Vec<(Entity, Duration)>);
+
+/// Creates an app with the given plugin, ticking the decay by 100 milliseconds each frame.
+fn app(plugin: DecayPlugin) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, plugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .init_resource::<Completed>()
+        .add_observer(
+            |trigger: Trigger<DecayCompleted>,
+             time: Res<Time>,
+             mut completed: ResMut<Completed>| {
+                let elapsed = time.elapsed();
+                completed
+                    .0
+                    .extend(trigger.iter().map(|&entity| (entity, elapsed)));
+            },
+        );
+    app
+}
+
+/// Runs the given number of frames.
+fn run(app: &mut App, frames: usize) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+/// Checks that the entity completed once, at the expected elapsed time within a second.
+fn assert_completed_at(app: &App, entity: Entity, expected: Duration) {
+    let completed = &app.world().resource::<Completed>().0;
+    assert_eq!(completed.len(), 1, "expected a single completion");
+
+    let (completed, elapsed) = completed[0];
+    assert_eq!(completed, entity);
+    assert!(
+        elapsed.abs_diff(expected) < Duration::from_secs(1),
+        "expected a completion at {expected:?}, got {elapsed:?}"
+    );
+}
+
+#[test]
+fn simulation_completes_long_lane_decays_on_time() {
+    let mut app =
+        app(DecayPlugin::default()
+            .with_long_lane(Duration::from_secs(60 * 60), Duration::from_secs(1)));
+    let entity = app
+        .world_mut()
+        .spawn((Decay, DecayDuration::new(Duration::from_secs(2 * DAY))))
+        .id();
+
+    // The first frame has no elapsed time, and the lane is checked after a second.
+    run(&mut app, 11);
+    assert!(app.world().get::<DecayLongLaneMember>(entity).is_some());
+
+    simulate(app.world_mut(), Duration::from_secs(3 * DAY));
+    assert_completed_at(&app, entity, Duration::from_secs(2 * DAY));
+    assert!(app.world().get::<DecayTimer>(entity).is_none());
+}
+
+#[test]
+fn simulation_completes_scanned_decays_on_time() {
+    let mut app = app(DecayPlugin::default().with_async_scan(Duration::from_secs(1)));
+    let entity = app
+        .world_mut()
+        .spawn((Decay, DecayDuration::new(Duration::from_secs(60 * 60))))
+        .id();
+    run(&mut app, 1);
+
+    simulate(app.world_mut(), Duration::from_secs(2 * 60 * 60));
+    assert_completed_at(&app, entity, Duration::from_secs(60 * 60));
+    assert!(app.world().get::<DecayTimer>(entity).is_none());
+}
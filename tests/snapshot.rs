@@ -0,0 +1,54 @@
+#![cfg(feature = "ron")]
+
+use ot_decay::{prelude::*, DecaySnapshot, DecaySnapshotError, DECAY_SNAPSHOT_VERSION};
+
+/// Parses the untyped value of a RON document.
+fn value(document: &str) -> ron::Value {
+    ron::from_str(document).unwrap()
+}
+
+/// Migration from version 0, whose decays were named `entries`.
+fn rename_entries(value: &mut ron::Value) {
+    let ron::Value::Map(map) = value else {
+        return;
+    };
+    if let Some(entries) = map.remove(&ron::Value::String("entries".to_string())) {
+        map.insert(ron::Value::String("decays".to_string()), entries);
+    }
+}
+
+#[test]
+fn snapshot_without_version_is_rejected() {
+    let mut value = value("(decays: [])");
+    assert_eq!(
+        DecaySnapshotMigrations::default().migrate(&mut value),
+        Err(DecaySnapshotError::MissingVersion)
+    );
+}
+
+#[test]
+fn snapshot_of_a_newer_version_is_rejected() {
+    let newer = DECAY_SNAPSHOT_VERSION + 1;
+    let mut value = value(&format!("(version: {newer}, decays: [])"));
+    assert_eq!(
+        DecaySnapshotMigrations::default().migrate(&mut value),
+        Err(DecaySnapshotError::Newer(newer))
+    );
+}
+
+#[test]
+fn snapshot_without_a_migration_is_rejected() {
+    let mut value = value("(version: 0, entries: [])");
+    assert_eq!(
+        DecaySnapshotMigrations::default().migrate(&mut value),
+        Err(DecaySnapshotError::MissingMigration(0))
+    );
+}
+
+#[test]
+fn snapshot_is_migrated_to_the_current_version() {
+    let migrations = DecaySnapshotMigrations::default().with_migration(0, rename_entries);
+    let snapshot = DecaySnapshot::from_ron("(version: 0, entries: [])", &migrations).unwrap();
+    assert!(snapshot.is_current());
+    assert!(snapshot.decays.is_empty());
+}